@@ -0,0 +1,390 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::ast::{ASTNode, InterpolationPart, LiteralValue, Span};
+use crate::error::{DiagnosticSink, ErrorCatalog, MeowLangError};
+use crate::interner::{Interner, Symbol};
+
+/// Names the VM's `call_builtin` recognizes directly, plus `plier` (fold),
+/// which the compiler special-cases instead of routing through it. Kept in
+/// sync with `VM::call_builtin` by hand since builtins aren't `FunctionDef`s
+/// and so never show up in `Analyzer::functions`.
+const BUILTIN_FUNCTIONS: &[&str] = &[
+    "ecrire", "demander_texte", "demander_nombre", "minuscule", "majuscule",
+    "longueur", "aleatoire", "aleatoire_normal", "aleatoire_exponentiel",
+    "aleatoire_flottant", "sqrt", "abs", "round", "floor", "ceil",
+    "attendre", "plier", "lancer", "attendre_tache", "tache_prete",
+    "caractere_a", "sous_chaine", "inverser",
+];
+
+/// Whether `name` names a builtin — shared with `Resolver`, which needs the
+/// same answer to avoid flagging a builtin call as an undefined reference.
+pub(crate) fn is_builtin_function(name: &str) -> bool {
+    BUILTIN_FUNCTIONS.contains(&name)
+}
+
+/// A coarse type inferred only for expressions whose shape makes the type
+/// obvious at parse time (a literal, a list/dict node). Anything else —
+/// identifiers, call results, the output of a binary op — is simply not
+/// statically known here, so `check_expr` returns `None` for it rather than
+/// guessing. That keeps the checks below free of false positives on code
+/// whose real type only shows up at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InferredType {
+    Number,
+    String,
+    Boolean,
+    List,
+    Dict,
+    Function,
+    Unknown,
+}
+
+impl InferredType {
+    fn display(self) -> &'static str {
+        match self {
+            InferredType::Number => "Nombre",
+            InferredType::String => "Texte",
+            InferredType::Boolean => "Booléen",
+            InferredType::List => "Liste",
+            InferredType::Dict => "Dictionnaire",
+            InferredType::Function => "Fonction",
+            InferredType::Unknown => "Inconnu",
+        }
+    }
+}
+
+fn is_arithmetic_operator(operator: &str) -> bool {
+    matches!(operator, "+" | "-" | "*" | "/" | "//" | "%" | "**")
+}
+
+/// Walks the parsed `ASTNode` tree once, before compilation, so mistakes in
+/// branches that wouldn't otherwise run this time still get reported. Tracks
+/// a flat per-function scope of assigned names (mirroring the compiler's
+/// `SlotTable`, which allocates a slot the first time it sees a name
+/// anywhere in a function regardless of textual order) and flags undefined
+/// identifiers, calls to unknown functions or with the wrong arity, and
+/// arithmetic/indexing applied to a statically-known literal of the wrong
+/// shape. Reuses the same `DiagnosticSink` the parser does, so one run can
+/// report every mistake instead of just the first.
+pub struct Analyzer<'a> {
+    interner: &'a Interner,
+    filename: String,
+    source_lines: Vec<String>,
+    sink: DiagnosticSink,
+    functions: HashMap<Symbol, usize>,
+}
+
+impl<'a> Analyzer<'a> {
+    pub fn new(interner: &'a Interner, filename: String, source_lines: Vec<String>) -> Self {
+        Analyzer {
+            interner,
+            filename,
+            source_lines,
+            sink: DiagnosticSink::default(),
+            functions: HashMap::new(),
+        }
+    }
+
+    pub fn analyze(mut self, program: &ASTNode) -> Vec<MeowLangError> {
+        let statements = match program {
+            ASTNode::Program { statements, .. } => statements,
+            _ => return Vec::new(),
+        };
+
+        self.collect_functions(statements);
+
+        let mut scope = HashSet::new();
+        self.collect_assigned(statements, &mut scope);
+        self.check_block(statements, &scope);
+
+        self.sink.errors
+    }
+
+    /// Hoists every `FunctionDef` found anywhere in `statements`, however
+    /// deeply nested, mirroring `Compiler::hoist_functions` so a function
+    /// can be called before its definition is reached textually.
+    fn collect_functions(&mut self, statements: &[ASTNode]) {
+        for stmt in statements {
+            match stmt {
+                ASTNode::FunctionDef { name, parameters, body, .. } => {
+                    self.functions.insert(*name, parameters.len());
+                    self.collect_functions(body);
+                },
+                ASTNode::IfStatement { then_block, elif_blocks, else_block, .. } => {
+                    self.collect_functions(then_block);
+                    for (_, body) in elif_blocks {
+                        self.collect_functions(body);
+                    }
+                    if let Some(body) = else_block {
+                        self.collect_functions(body);
+                    }
+                },
+                ASTNode::WhileLoop { body, .. }
+                | ASTNode::RepeatLoop { body, .. }
+                | ASTNode::ForEachLoop { body, .. } => self.collect_functions(body),
+                ASTNode::TryExcept { try_block, handlers, .. } => {
+                    self.collect_functions(try_block);
+                    for handler in handlers {
+                        self.collect_functions(&handler.body);
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    /// Gathers every name `statements` assigns somewhere in its body — not a
+    /// flow analysis, just "is this name ever bound here" — so a variable
+    /// assigned in one `si` branch isn't flagged as undefined when read in
+    /// another. Stops at a nested `FunctionDef`, which gets its own scope in
+    /// `check_block` instead.
+    fn collect_assigned(&self, statements: &[ASTNode], scope: &mut HashSet<Symbol>) {
+        for stmt in statements {
+            match stmt {
+                ASTNode::Assignment { name, .. } => {
+                    scope.insert(*name);
+                },
+                ASTNode::IfStatement { then_block, elif_blocks, else_block, .. } => {
+                    self.collect_assigned(then_block, scope);
+                    for (_, body) in elif_blocks {
+                        self.collect_assigned(body, scope);
+                    }
+                    if let Some(body) = else_block {
+                        self.collect_assigned(body, scope);
+                    }
+                },
+                ASTNode::WhileLoop { body, .. } | ASTNode::RepeatLoop { body, .. } => {
+                    self.collect_assigned(body, scope);
+                },
+                ASTNode::ForEachLoop { iterator, body, .. } => {
+                    scope.insert(*iterator);
+                    self.collect_assigned(body, scope);
+                },
+                ASTNode::TryExcept { try_block, handlers, .. } => {
+                    self.collect_assigned(try_block, scope);
+                    for handler in handlers {
+                        if let Some(binding) = handler.binding {
+                            scope.insert(binding);
+                        }
+                        self.collect_assigned(&handler.body, scope);
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    fn check_block(&mut self, statements: &[ASTNode], scope: &HashSet<Symbol>) {
+        for stmt in statements {
+            match stmt {
+                ASTNode::ExpressionStatement { expression, .. } => {
+                    self.check_expr(expression, scope);
+                },
+                ASTNode::Assignment { value, .. } => {
+                    self.check_expr(value, scope);
+                },
+                ASTNode::IfStatement { condition, then_block, elif_blocks, else_block, .. } => {
+                    self.check_expr(condition, scope);
+                    self.check_block(then_block, scope);
+                    for (elif_condition, body) in elif_blocks {
+                        self.check_expr(elif_condition, scope);
+                        self.check_block(body, scope);
+                    }
+                    if let Some(body) = else_block {
+                        self.check_block(body, scope);
+                    }
+                },
+                ASTNode::WhileLoop { condition, body, .. } => {
+                    self.check_expr(condition, scope);
+                    self.check_block(body, scope);
+                },
+                ASTNode::RepeatLoop { count, body, .. } => {
+                    self.check_expr(count, scope);
+                    self.check_block(body, scope);
+                },
+                ASTNode::ForEachLoop { iterable, body, .. } => {
+                    self.check_expr(iterable, scope);
+                    self.check_block(body, scope);
+                },
+                ASTNode::FunctionDef { parameters, body, .. } => {
+                    let mut func_scope: HashSet<Symbol> = parameters.iter().cloned().collect();
+                    self.collect_assigned(body, &mut func_scope);
+                    self.check_block(body, &func_scope);
+                },
+                ASTNode::ReturnStatement { value, .. } => {
+                    if let Some(value) = value {
+                        self.check_expr(value, scope);
+                    }
+                },
+                ASTNode::BreakStatement { .. } | ASTNode::ContinueStatement { .. } => {},
+                ASTNode::IndexAssignment { object, index, value, span } => {
+                    let object_type = self.check_expr(object, scope);
+                    self.check_expr(index, scope);
+                    self.check_expr(value, scope);
+                    self.check_index_target(object, object_type, span);
+                },
+                ASTNode::TryExcept { try_block, handlers, .. } => {
+                    self.check_block(try_block, scope);
+                    for handler in handlers {
+                        self.check_block(&handler.body, scope);
+                    }
+                },
+                _ => {
+                    self.check_expr(stmt, scope);
+                },
+            }
+        }
+    }
+
+    /// Checks an expression and, where its shape makes the type obvious at
+    /// parse time, returns it — `None` means "not statically known", not
+    /// "untyped".
+    fn check_expr(&mut self, expr: &ASTNode, scope: &HashSet<Symbol>) -> Option<InferredType> {
+        match expr {
+            ASTNode::Literal { value, .. } => Some(match value {
+                LiteralValue::Number(_) | LiteralValue::Integer(_) => InferredType::Number,
+                LiteralValue::String(_) => InferredType::String,
+                LiteralValue::Char(_) => InferredType::String,
+                LiteralValue::Boolean(_) => InferredType::Boolean,
+                LiteralValue::None => InferredType::Unknown,
+            }),
+            ASTNode::Identifier { name, span } => {
+                if !scope.contains(name) && !self.functions.contains_key(name) && !self.is_builtin(*name) {
+                    let var_name = self.interner.resolve(*name).to_string();
+                    self.push_error("E200", span, &[("var_name", var_name)]);
+                }
+                self.functions.contains_key(name).then_some(InferredType::Function)
+            },
+            ASTNode::BinaryOp { left, operator, right, .. } => {
+                let left_type = self.check_expr(left, scope);
+                let right_type = self.check_expr(right, scope);
+                if is_arithmetic_operator(operator) {
+                    self.check_arithmetic_operand(left.span(), left_type, operator);
+                    self.check_arithmetic_operand(right.span(), right_type, operator);
+                }
+                None
+            },
+            ASTNode::UnaryOp { operator, operand, .. } => {
+                let operand_type = self.check_expr(operand, scope);
+                if operator == "-" {
+                    self.check_arithmetic_operand(operand.span(), operand_type, operator);
+                }
+                None
+            },
+            ASTNode::FunctionCall { name, arguments, span, .. } => {
+                for argument in arguments {
+                    self.check_expr(argument, scope);
+                }
+
+                let name_str = self.interner.resolve(*name);
+                if BUILTIN_FUNCTIONS.contains(&name_str) {
+                    // Builtins have no declared `FunctionDef` arity to check against.
+                } else if name_str == "plier" {
+                    if arguments.len() != 3 {
+                        self.push_error("E601", span, &[
+                            ("expected", "3".to_string()),
+                            ("received", arguments.len().to_string()),
+                        ]);
+                    }
+                } else if name_str == "lancer" {
+                    if arguments.is_empty() {
+                        self.push_error("E601", span, &[
+                            ("expected", "1+".to_string()),
+                            ("received", "0".to_string()),
+                        ]);
+                    }
+                } else if let Some(&arity) = self.functions.get(name) {
+                    if arguments.len() != arity {
+                        self.push_error("E601", span, &[
+                            ("expected", arity.to_string()),
+                            ("received", arguments.len().to_string()),
+                        ]);
+                    }
+                } else {
+                    self.push_error("E600", span, &[("func_name", name_str.to_string())]);
+                }
+
+                None
+            },
+            ASTNode::ListNode { elements, .. } => {
+                for element in elements {
+                    self.check_expr(element, scope);
+                }
+                Some(InferredType::List)
+            },
+            ASTNode::DictNode { pairs, .. } => {
+                for (key, value) in pairs {
+                    self.check_expr(key, scope);
+                    self.check_expr(value, scope);
+                }
+                Some(InferredType::Dict)
+            },
+            ASTNode::IndexAccess { object, index, span } => {
+                let object_type = self.check_expr(object, scope);
+                self.check_expr(index, scope);
+                self.check_index_target(object, object_type, span);
+                None
+            },
+            ASTNode::Interpolation { parts, .. } => {
+                for part in parts {
+                    if let InterpolationPart::Expr(expr) = part {
+                        self.check_expr(expr, scope);
+                    }
+                }
+                Some(InferredType::String)
+            },
+            _ => None,
+        }
+    }
+
+    /// Flags an arithmetic operand whose type is statically known (a
+    /// literal, a list/dict node) and wrong for `operator`. `+` also accepts
+    /// `String`, since `VM::add_values` treats it as concatenation.
+    fn check_arithmetic_operand(&mut self, span: &Span, operand_type: Option<InferredType>, operator: &str) {
+        let Some(operand_type) = operand_type else { return };
+
+        let ok = if operator == "+" {
+            matches!(operand_type, InferredType::Number | InferredType::String)
+        } else {
+            matches!(operand_type, InferredType::Number)
+        };
+
+        if !ok {
+            self.push_error("E202", span, &[
+                ("type1", operand_type.display().to_string()),
+                ("type2", InferredType::Number.display().to_string()),
+            ]);
+        }
+    }
+
+    /// Flags indexing a literal (list/dict node or other literal) whose
+    /// statically-known type isn't `Liste` or `Dictionnaire` — the only
+    /// things `Instr::Index` accepts.
+    fn check_index_target(&mut self, object: &ASTNode, object_type: Option<InferredType>, span: &Span) {
+        if !matches!(object, ASTNode::Literal { .. } | ASTNode::ListNode { .. } | ASTNode::DictNode { .. }) {
+            return;
+        }
+
+        if let Some(object_type) = object_type {
+            if !matches!(object_type, InferredType::List | InferredType::Dict) {
+                self.push_error("E202", span, &[
+                    ("type1", object_type.display().to_string()),
+                    ("type2", InferredType::List.display().to_string()),
+                ]);
+            }
+        }
+    }
+
+    fn is_builtin(&self, name: Symbol) -> bool {
+        is_builtin_function(self.interner.resolve(name))
+    }
+
+    fn push_error(&mut self, code: &str, span: &Span, extras: &[(&str, String)]) {
+        let mut error = MeowLangError::new(ErrorCatalog::get(code), self.filename.clone(), span.start.line, span.start.column)
+            .with_context(&self.source_lines);
+        for (key, value) in extras {
+            error = error.with_extra((*key).to_string(), value.clone());
+        }
+        self.sink.push(error);
+    }
+}
@@ -1,4 +1,5 @@
 use std::fmt;
+use crate::interner::Symbol;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Position {
@@ -18,153 +19,249 @@ impl fmt::Display for Position {
     }
 }
 
+/// A source range, from where a token or node begins to where it ends.
+/// Replaces the old single-point `Position` for anything that needs to
+/// underline more than one character (error carets, formatting, tooling).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Span {
+    pub fn new(start: Position, end: Position) -> Self {
+        Span { start, end }
+    }
+
+    /// A zero-width span, for nodes synthesized at a single point.
+    pub fn point(position: Position) -> Self {
+        Span {
+            end: position.clone(),
+            start: position,
+        }
+    }
+
+    /// The smallest span covering both `self` and `other`, assuming
+    /// `other` comes at or after `self` in the source.
+    pub fn merge(&self, other: &Span) -> Span {
+        Span::new(self.start.clone(), other.end.clone())
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start)
+        } else {
+            write!(f, "{}-{}", self.start, self.end)
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ASTNode {
     Program {
         statements: Vec<ASTNode>,
-        position: Position,
+        span: Span,
     },
-    
+
     Literal {
         value: LiteralValue,
-        position: Position,
+        span: Span,
+    },
+
+    /// An interpolated string literal, e.g. `"Bonjour {nom} !"` — `parts`
+    /// alternates literal text with embedded expressions, in source order.
+    Interpolation {
+        parts: Vec<InterpolationPart>,
+        span: Span,
     },
-    
+
     Identifier {
-        name: String,
-        position: Position,
+        name: Symbol,
+        span: Span,
     },
-    
+
     BinaryOp {
         left: Box<ASTNode>,
         operator: String,
         right: Box<ASTNode>,
-        position: Position,
+        span: Span,
     },
-    
+
     UnaryOp {
         operator: String,
         operand: Box<ASTNode>,
-        position: Position,
+        span: Span,
     },
-    
+
     Assignment {
-        name: String,
+        name: Symbol,
         value: Box<ASTNode>,
-        position: Position,
+        span: Span,
     },
-    
+
     FunctionCall {
-        name: String,
+        name: Symbol,
         arguments: Vec<ASTNode>,
-        position: Position,
+        span: Span,
+        /// Comment lines that sat directly above this call in source,
+        /// carried over from the call-name token's `Token::leading_trivia`
+        /// so `formatter::format` can reprint them. Empty unless the user
+        /// actually wrote a comment there.
+        leading_trivia: Vec<String>,
     },
-    
+
     IfStatement {
         condition: Box<ASTNode>,
         then_block: Vec<ASTNode>,
         elif_blocks: Vec<(ASTNode, Vec<ASTNode>)>,
         else_block: Option<Vec<ASTNode>>,
-        position: Position,
+        span: Span,
     },
-    
+
     WhileLoop {
         condition: Box<ASTNode>,
         body: Vec<ASTNode>,
-        position: Position,
+        span: Span,
     },
-    
+
     RepeatLoop {
         count: Box<ASTNode>,
         body: Vec<ASTNode>,
-        position: Position,
+        span: Span,
     },
-    
+
     ForEachLoop {
-        iterator: String,
+        iterator: Symbol,
         iterable: Box<ASTNode>,
         body: Vec<ASTNode>,
-        position: Position,
+        span: Span,
     },
-    
+
     FunctionDef {
-        name: String,
-        parameters: Vec<String>,
+        name: Symbol,
+        parameters: Vec<Symbol>,
         body: Vec<ASTNode>,
-        position: Position,
+        span: Span,
     },
-    
+
     ReturnStatement {
         value: Option<Box<ASTNode>>,
-        position: Position,
+        span: Span,
+    },
+
+    BreakStatement {
+        span: Span,
     },
-    
+
+    ContinueStatement {
+        span: Span,
+    },
+
     ListNode {
         elements: Vec<ASTNode>,
-        position: Position,
+        span: Span,
+        /// Comment lines that sat directly above this list's opening
+        /// `liste(` in source — see `FunctionCall::leading_trivia`.
+        leading_trivia: Vec<String>,
     },
-    
+
     DictNode {
         pairs: Vec<(ASTNode, ASTNode)>,
-        position: Position,
+        span: Span,
     },
-    
+
     IndexAccess {
         object: Box<ASTNode>,
         index: Box<ASTNode>,
-        position: Position,
+        span: Span,
     },
-    
+
     IndexAssignment {
         object: Box<ASTNode>,
         index: Box<ASTNode>,
         value: Box<ASTNode>,
-        position: Position,
+        span: Span,
     },
-    
+
     TryExcept {
         try_block: Vec<ASTNode>,
-        except_block: Vec<ASTNode>,
-        position: Position,
+        handlers: Vec<ExceptHandler>,
+        span: Span,
     },
-    
+
     ExpressionStatement {
         expression: Box<ASTNode>,
-        position: Position,
+        span: Span,
     },
 }
 
+/// One `sauf erreur` clause of a `TryExcept`. `code_filter` restricts the
+/// handler to a single error code (e.g. `"E500"`); `None` catches anything.
+/// `binding` names the variable (if any) that receives the caught error as
+/// a `Value::Dict` with `code`/`message`/`ligne`/`colonne` keys.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExceptHandler {
+    pub code_filter: Option<String>,
+    pub binding: Option<Symbol>,
+    pub body: Vec<ASTNode>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum LiteralValue {
-    String(String),
+    String(Symbol),
     Number(f64),
     Integer(i64),
+    /// A `'c'` character literal. There's no dedicated runtime character
+    /// type — downstream stages (compiler, codegen) treat it exactly like
+    /// a one-character `String`, same as indexing a string already yields
+    /// a one-character string rather than a distinct char value.
+    Char(char),
     Boolean(bool),
     None,
 }
 
+/// One piece of an `Interpolation` node. `Expr` is parsed from the raw
+/// source text the lexer captured between `{`/`}` (see
+/// `InterpolationSegment` in `token.rs`) using the same `parse_expression`
+/// as everything else — interpolation isn't a separate expression grammar.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InterpolationPart {
+    Literal(Symbol),
+    Expr(Box<ASTNode>),
+}
+
 impl ASTNode {
-    pub fn position(&self) -> &Position {
+    pub fn span(&self) -> &Span {
         match self {
-            ASTNode::Program { position, .. } => position,
-            ASTNode::Literal { position, .. } => position,
-            ASTNode::Identifier { position, .. } => position,
-            ASTNode::BinaryOp { position, .. } => position,
-            ASTNode::UnaryOp { position, .. } => position,
-            ASTNode::Assignment { position, .. } => position,
-            ASTNode::FunctionCall { position, .. } => position,
-            ASTNode::IfStatement { position, .. } => position,
-            ASTNode::WhileLoop { position, .. } => position,
-            ASTNode::RepeatLoop { position, .. } => position,
-            ASTNode::ForEachLoop { position, .. } => position,
-            ASTNode::FunctionDef { position, .. } => position,
-            ASTNode::ReturnStatement { position, .. } => position,
-            ASTNode::ListNode { position, .. } => position,
-            ASTNode::DictNode { position, .. } => position,
-            ASTNode::IndexAccess { position, .. } => position,
-            ASTNode::IndexAssignment { position, .. } => position,
-            ASTNode::TryExcept { position, .. } => position,
-            ASTNode::ExpressionStatement { position, .. } => position,
+            ASTNode::Program { span, .. } => span,
+            ASTNode::Literal { span, .. } => span,
+            ASTNode::Interpolation { span, .. } => span,
+            ASTNode::Identifier { span, .. } => span,
+            ASTNode::BinaryOp { span, .. } => span,
+            ASTNode::UnaryOp { span, .. } => span,
+            ASTNode::Assignment { span, .. } => span,
+            ASTNode::FunctionCall { span, .. } => span,
+            ASTNode::IfStatement { span, .. } => span,
+            ASTNode::WhileLoop { span, .. } => span,
+            ASTNode::RepeatLoop { span, .. } => span,
+            ASTNode::ForEachLoop { span, .. } => span,
+            ASTNode::FunctionDef { span, .. } => span,
+            ASTNode::ReturnStatement { span, .. } => span,
+            ASTNode::BreakStatement { span } => span,
+            ASTNode::ContinueStatement { span } => span,
+            ASTNode::ListNode { span, .. } => span,
+            ASTNode::DictNode { span, .. } => span,
+            ASTNode::IndexAccess { span, .. } => span,
+            ASTNode::IndexAssignment { span, .. } => span,
+            ASTNode::TryExcept { span, .. } => span,
+            ASTNode::ExpressionStatement { span, .. } => span,
         }
     }
+
+    /// Convenience accessor for code that only cares where a node starts.
+    pub fn position(&self) -> &Position {
+        &self.span().start
+    }
 }
@@ -0,0 +1,646 @@
+//! Hand-rolled JSON serialization for `ASTNode`/`LiteralValue`/`Position`
+//! and for a raw `Token` stream, backing the `--dump-ast`/`--dump-tokens`
+//! CLI flags (see `main.rs`) so external tooling — formatters, linters,
+//! editor plugins — can consume a parsed MeowLang program without
+//! re-implementing the parser.
+//!
+//! The natural way to get there would be `#[derive(serde::Serialize)]` on
+//! `ASTNode`, `LiteralValue` and `Position`, matching how Dust and similar
+//! projects expose their AST. This crate has no `Cargo.toml` to add
+//! `serde` as a dependency to, and more to the point, `MeowLangError`
+//! already solved the identical problem (a diagnostic needs to reach an
+//! editor/LSP as structured JSON — see chunk0-5) by hand-writing
+//! `MeowLangError::to_json` rather than deriving anything, because a
+//! derive can't resolve a `Symbol` back to the source text it interned
+//! without being handed the `Interner` — a derive-based `Serialize` would
+//! either serialize the raw `u32` (useless to a reader outside this
+//! process) or need a `serde(with = ...)` shim per `Symbol` field anyway.
+//! Following that precedent, this module mirrors `error::json_string`'s
+//! escaping and builds the same kind of plain `format!`-assembled JSON by
+//! hand, threading the `Interner` through every call so `Symbol`s resolve
+//! to real text.
+//!
+//! A later request (chunk5-5) asked for this same `--dump-ast` output
+//! again, via `serde::Serialize`/`Deserialize` derives instead. The
+//! derives are still off the table for the reason above, but the actual
+//! deliverable — a stable JSON tree for editor integrations, test
+//! snapshots, and visualizers to consume without re-implementing the
+//! parser — already exists here and in `tokens_to_json` below. The
+//! *deserialize* half now lives here too: `ast_from_json` hand-parses the
+//! exact shape `ast_to_json` emits back into an `ASTNode`, the same way
+//! `ast_to_json` hand-emits it, so `tests/snapshot_tests.rs`'s round-trip
+//! check has a real JSON-to-`ASTNode` path to go through instead of only
+//! comparing two dumps as strings.
+
+use crate::ast::{ASTNode, ExceptHandler, InterpolationPart, LiteralValue, Position, Span};
+use crate::error::json_string;
+use crate::interner::{Interner, Symbol};
+use crate::token::{InterpolationSegment, Token, TokenValue};
+
+fn position_json(position: &Position) -> String {
+    format!("{{\"line\":{},\"column\":{}}}", position.line, position.column)
+}
+
+fn span_json(span: &Span) -> String {
+    format!("{{\"start\":{},\"end\":{}}}", position_json(&span.start), position_json(&span.end))
+}
+
+fn symbol_json(symbol: Symbol, interner: &Interner) -> String {
+    json_string(interner.resolve(symbol))
+}
+
+fn literal_json(value: &LiteralValue, interner: &Interner) -> String {
+    match value {
+        LiteralValue::String(s) => format!("{{\"kind\":\"String\",\"value\":{}}}", symbol_json(*s, interner)),
+        LiteralValue::Number(n) => format!("{{\"kind\":\"Number\",\"value\":{}}}", n),
+        LiteralValue::Integer(i) => format!("{{\"kind\":\"Integer\",\"value\":{}}}", i),
+        LiteralValue::Char(c) => format!("{{\"kind\":\"Char\",\"value\":{}}}", json_string(&c.to_string())),
+        LiteralValue::Boolean(b) => format!("{{\"kind\":\"Boolean\",\"value\":{}}}", b),
+        LiteralValue::None => "{\"kind\":\"None\"}".to_string(),
+    }
+}
+
+fn block_json(statements: &[ASTNode], interner: &Interner) -> String {
+    let items: Vec<String> = statements.iter().map(|s| ast_to_json(s, interner)).collect();
+    format!("[{}]", items.join(","))
+}
+
+fn handler_json(handler: &ExceptHandler, interner: &Interner) -> String {
+    let code_filter = match &handler.code_filter {
+        Some(code) => json_string(code),
+        None => "null".to_string(),
+    };
+    let binding = match handler.binding {
+        Some(name) => symbol_json(name, interner),
+        None => "null".to_string(),
+    };
+    format!(
+        "{{\"code_filter\":{},\"binding\":{},\"body\":{}}}",
+        code_filter,
+        binding,
+        block_json(&handler.body, interner),
+    )
+}
+
+/// Serializes `node` (and everything under it) as a single JSON value,
+/// shaped `{"type": "<variant name>", ...fields}` — one object key per AST
+/// field, with child nodes/blocks nested the same way.
+pub fn ast_to_json(node: &ASTNode, interner: &Interner) -> String {
+    match node {
+        ASTNode::Program { statements, span } => format!(
+            "{{\"type\":\"Program\",\"statements\":{},\"span\":{}}}",
+            block_json(statements, interner), span_json(span),
+        ),
+        ASTNode::Literal { value, span } => format!(
+            "{{\"type\":\"Literal\",\"value\":{},\"span\":{}}}",
+            literal_json(value, interner), span_json(span),
+        ),
+        ASTNode::Interpolation { parts, span } => {
+            let items: Vec<String> = parts.iter().map(|part| match part {
+                InterpolationPart::Literal(s) => format!("{{\"kind\":\"Literal\",\"value\":{}}}", symbol_json(*s, interner)),
+                InterpolationPart::Expr(expr) => format!("{{\"kind\":\"Expr\",\"value\":{}}}", ast_to_json(expr, interner)),
+            }).collect();
+            format!("{{\"type\":\"Interpolation\",\"parts\":[{}],\"span\":{}}}", items.join(","), span_json(span))
+        },
+        ASTNode::Identifier { name, span } => format!(
+            "{{\"type\":\"Identifier\",\"name\":{},\"span\":{}}}",
+            symbol_json(*name, interner), span_json(span),
+        ),
+        ASTNode::BinaryOp { left, operator, right, span } => format!(
+            "{{\"type\":\"BinaryOp\",\"left\":{},\"operator\":{},\"right\":{},\"span\":{}}}",
+            ast_to_json(left, interner), json_string(operator), ast_to_json(right, interner), span_json(span),
+        ),
+        ASTNode::UnaryOp { operator, operand, span } => format!(
+            "{{\"type\":\"UnaryOp\",\"operator\":{},\"operand\":{},\"span\":{}}}",
+            json_string(operator), ast_to_json(operand, interner), span_json(span),
+        ),
+        ASTNode::Assignment { name, value, span } => format!(
+            "{{\"type\":\"Assignment\",\"name\":{},\"value\":{},\"span\":{}}}",
+            symbol_json(*name, interner), ast_to_json(value, interner), span_json(span),
+        ),
+        ASTNode::FunctionCall { name, arguments, span, .. } => format!(
+            "{{\"type\":\"FunctionCall\",\"name\":{},\"arguments\":{},\"span\":{}}}",
+            symbol_json(*name, interner), block_json(arguments, interner), span_json(span),
+        ),
+        ASTNode::IfStatement { condition, then_block, elif_blocks, else_block, span } => {
+            let elifs: Vec<String> = elif_blocks.iter().map(|(cond, body)| {
+                format!("{{\"condition\":{},\"body\":{}}}", ast_to_json(cond, interner), block_json(body, interner))
+            }).collect();
+            let else_json = match else_block {
+                Some(body) => block_json(body, interner),
+                None => "null".to_string(),
+            };
+            format!(
+                "{{\"type\":\"IfStatement\",\"condition\":{},\"then_block\":{},\"elif_blocks\":[{}],\"else_block\":{},\"span\":{}}}",
+                ast_to_json(condition, interner), block_json(then_block, interner), elifs.join(","), else_json, span_json(span),
+            )
+        },
+        ASTNode::WhileLoop { condition, body, span } => format!(
+            "{{\"type\":\"WhileLoop\",\"condition\":{},\"body\":{},\"span\":{}}}",
+            ast_to_json(condition, interner), block_json(body, interner), span_json(span),
+        ),
+        ASTNode::RepeatLoop { count, body, span } => format!(
+            "{{\"type\":\"RepeatLoop\",\"count\":{},\"body\":{},\"span\":{}}}",
+            ast_to_json(count, interner), block_json(body, interner), span_json(span),
+        ),
+        ASTNode::ForEachLoop { iterator, iterable, body, span } => format!(
+            "{{\"type\":\"ForEachLoop\",\"iterator\":{},\"iterable\":{},\"body\":{},\"span\":{}}}",
+            symbol_json(*iterator, interner), ast_to_json(iterable, interner), block_json(body, interner), span_json(span),
+        ),
+        ASTNode::FunctionDef { name, parameters, body, span } => {
+            let params: Vec<String> = parameters.iter().map(|p| symbol_json(*p, interner)).collect();
+            format!(
+                "{{\"type\":\"FunctionDef\",\"name\":{},\"parameters\":[{}],\"body\":{},\"span\":{}}}",
+                symbol_json(*name, interner), params.join(","), block_json(body, interner), span_json(span),
+            )
+        },
+        ASTNode::ReturnStatement { value, span } => {
+            let value_json = match value {
+                Some(v) => ast_to_json(v, interner),
+                None => "null".to_string(),
+            };
+            format!("{{\"type\":\"ReturnStatement\",\"value\":{},\"span\":{}}}", value_json, span_json(span))
+        },
+        ASTNode::BreakStatement { span } => format!("{{\"type\":\"BreakStatement\",\"span\":{}}}", span_json(span)),
+        ASTNode::ContinueStatement { span } => format!("{{\"type\":\"ContinueStatement\",\"span\":{}}}", span_json(span)),
+        ASTNode::ListNode { elements, span, .. } => format!(
+            "{{\"type\":\"ListNode\",\"elements\":{},\"span\":{}}}",
+            block_json(elements, interner), span_json(span),
+        ),
+        ASTNode::DictNode { pairs, span } => {
+            let items: Vec<String> = pairs.iter().map(|(key, value)| {
+                format!("{{\"key\":{},\"value\":{}}}", ast_to_json(key, interner), ast_to_json(value, interner))
+            }).collect();
+            format!("{{\"type\":\"DictNode\",\"pairs\":[{}],\"span\":{}}}", items.join(","), span_json(span))
+        },
+        ASTNode::IndexAccess { object, index, span } => format!(
+            "{{\"type\":\"IndexAccess\",\"object\":{},\"index\":{},\"span\":{}}}",
+            ast_to_json(object, interner), ast_to_json(index, interner), span_json(span),
+        ),
+        ASTNode::IndexAssignment { object, index, value, span } => format!(
+            "{{\"type\":\"IndexAssignment\",\"object\":{},\"index\":{},\"value\":{},\"span\":{}}}",
+            ast_to_json(object, interner), ast_to_json(index, interner), ast_to_json(value, interner), span_json(span),
+        ),
+        ASTNode::TryExcept { try_block, handlers, span } => {
+            let handler_list: Vec<String> = handlers.iter().map(|h| handler_json(h, interner)).collect();
+            format!(
+                "{{\"type\":\"TryExcept\",\"try_block\":{},\"handlers\":[{}],\"span\":{}}}",
+                block_json(try_block, interner), handler_list.join(","), span_json(span),
+            )
+        },
+        ASTNode::ExpressionStatement { expression, span } => format!(
+            "{{\"type\":\"ExpressionStatement\",\"expression\":{},\"span\":{}}}",
+            ast_to_json(expression, interner), span_json(span),
+        ),
+    }
+}
+
+fn token_value_json(value: &TokenValue, interner: &Interner) -> String {
+    match value {
+        TokenValue::None => "null".to_string(),
+        TokenValue::String(s) => symbol_json(*s, interner),
+        TokenValue::Number(n) => n.to_string(),
+        TokenValue::Integer(i) => i.to_string(),
+        TokenValue::Char(c) => json_string(&c.to_string()),
+        TokenValue::Boolean(b) => b.to_string(),
+        TokenValue::Indent(n) => n.to_string(),
+        TokenValue::Interpolation(segments) => {
+            let items: Vec<String> = segments.iter().map(|segment| match segment {
+                InterpolationSegment::Literal(s) => format!("{{\"kind\":\"Literal\",\"value\":{}}}", symbol_json(*s, interner)),
+                InterpolationSegment::Expr(source) => format!("{{\"kind\":\"Expr\",\"value\":{}}}", json_string(source)),
+            }).collect();
+            format!("[{}]", items.join(","))
+        },
+    }
+}
+
+/// Serializes a whole token stream as a JSON array, one object per
+/// `Token`. `token_type` is rendered via its `Debug` output — `TokenType`
+/// is a plain, data-less enum, so that's already the exact variant name.
+pub fn tokens_to_json(tokens: &[Token], interner: &Interner) -> String {
+    let items: Vec<String> = tokens.iter().map(|token| {
+        format!(
+            "{{\"type\":{},\"value\":{},\"line\":{},\"column\":{},\"span\":{}}}",
+            json_string(&format!("{:?}", token.token_type)),
+            token_value_json(&token.value, interner),
+            token.line,
+            token.column,
+            span_json(&token.span),
+        )
+    }).collect();
+    format!("[{}]", items.join(","))
+}
+
+/// A generic JSON value, just enough to walk the shape `ast_to_json`
+/// emits back apart — no streaming reader, no `serde`, hand-rolled for
+/// the same reason the serializer above is (see this module's doc
+/// comment).
+#[derive(Debug, Clone, PartialEq)]
+enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    fn field(&self, key: &str) -> Result<&JsonValue, String> {
+        match self {
+            JsonValue::Object(fields) => fields.iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v)
+                .ok_or_else(|| format!("missing field {:?}", key)),
+            other => Err(format!("expected an object to read field {:?} from, got {:?}", key, other)),
+        }
+    }
+
+    fn as_str(&self) -> Result<&str, String> {
+        match self {
+            JsonValue::String(s) => Ok(s),
+            other => Err(format!("expected a string, got {:?}", other)),
+        }
+    }
+
+    fn as_f64(&self) -> Result<f64, String> {
+        match self {
+            JsonValue::Number(n) => Ok(*n),
+            other => Err(format!("expected a number, got {:?}", other)),
+        }
+    }
+
+    fn as_usize(&self) -> Result<usize, String> {
+        Ok(self.as_f64()? as usize)
+    }
+
+    fn as_array(&self) -> Result<&[JsonValue], String> {
+        match self {
+            JsonValue::Array(items) => Ok(items),
+            other => Err(format!("expected an array, got {:?}", other)),
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(self, JsonValue::Null)
+    }
+}
+
+/// A hand-rolled recursive-descent JSON parser, mirroring `Lexer`'s own
+/// `Vec<char>` + index scanning style rather than pulling in a JSON crate.
+struct JsonParser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl JsonParser {
+    fn new(source: &str) -> Self {
+        JsonParser { chars: source.chars().collect(), pos: 0 }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.peek();
+        if c.is_some() {
+            self.pos += 1;
+        }
+        c
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.advance() {
+            Some(c) if c == expected => Ok(()),
+            other => Err(format!("expected {:?}, got {:?} at byte {}", expected, other, self.pos)),
+        }
+    }
+
+    fn expect_literal(&mut self, literal: &str) -> Result<(), String> {
+        for expected in literal.chars() {
+            self.expect(expected)?;
+        }
+        Ok(())
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.parse_object(),
+            Some('[') => self.parse_array(),
+            Some('"') => Ok(JsonValue::String(self.parse_string()?)),
+            Some('t') => { self.expect_literal("true")?; Ok(JsonValue::Bool(true)) },
+            Some('f') => { self.expect_literal("false")?; Ok(JsonValue::Bool(false)) },
+            Some('n') => { self.expect_literal("null")?; Ok(JsonValue::Null) },
+            Some(c) if c == '-' || c.is_ascii_digit() => Ok(JsonValue::Number(self.parse_number()?)),
+            other => Err(format!("unexpected {:?} at byte {} while parsing a JSON value", other, self.pos)),
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, String> {
+        self.expect('{')?;
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.advance();
+            return Ok(JsonValue::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            let value = self.parse_value()?;
+            fields.push((key, value));
+            self.skip_whitespace();
+            match self.advance() {
+                Some(',') => continue,
+                Some('}') => break,
+                other => return Err(format!("expected ',' or '}}' in object, got {:?} at byte {}", other, self.pos)),
+            }
+        }
+        Ok(JsonValue::Object(fields))
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, String> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.advance();
+            return Ok(JsonValue::Array(items));
+        }
+        loop {
+            items.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.advance() {
+                Some(',') => continue,
+                Some(']') => break,
+                other => return Err(format!("expected ',' or ']' in array, got {:?} at byte {}", other, self.pos)),
+            }
+        }
+        Ok(JsonValue::Array(items))
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut out = String::new();
+        loop {
+            match self.advance() {
+                Some('"') => return Ok(out),
+                Some('\\') => match self.advance() {
+                    Some('"') => out.push('"'),
+                    Some('\\') => out.push('\\'),
+                    Some('/') => out.push('/'),
+                    Some('n') => out.push('\n'),
+                    Some('r') => out.push('\r'),
+                    Some('t') => out.push('\t'),
+                    Some('b') => out.push('\u{8}'),
+                    Some('f') => out.push('\u{c}'),
+                    Some('u') => {
+                        let mut code = 0u32;
+                        for _ in 0..4 {
+                            let digit = self.advance().and_then(|c| c.to_digit(16))
+                                .ok_or_else(|| format!("invalid \\u escape at byte {}", self.pos))?;
+                            code = code * 16 + digit;
+                        }
+                        out.push(char::from_u32(code).unwrap_or('\u{fffd}'));
+                    },
+                    other => return Err(format!("invalid escape {:?} at byte {}", other, self.pos)),
+                },
+                Some(c) => out.push(c),
+                None => return Err("unterminated string".to_string()),
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<f64, String> {
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.advance();
+        }
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.advance();
+        }
+        if self.peek() == Some('.') {
+            self.advance();
+            while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                self.advance();
+            }
+        }
+        if matches!(self.peek(), Some('e') | Some('E')) {
+            self.advance();
+            if matches!(self.peek(), Some('+') | Some('-')) {
+                self.advance();
+            }
+            while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                self.advance();
+            }
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse::<f64>().map_err(|e| format!("invalid number {:?}: {}", text, e))
+    }
+}
+
+fn position_from_json(value: &JsonValue) -> Result<Position, String> {
+    Ok(Position::new(value.field("line")?.as_usize()?, value.field("column")?.as_usize()?))
+}
+
+fn span_from_json(value: &JsonValue) -> Result<Span, String> {
+    Ok(Span::new(position_from_json(value.field("start")?)?, position_from_json(value.field("end")?)?))
+}
+
+fn symbol_from_json(value: &JsonValue, interner: &mut Interner) -> Result<Symbol, String> {
+    Ok(interner.intern(value.as_str()?))
+}
+
+fn literal_from_json(value: &JsonValue, interner: &mut Interner) -> Result<LiteralValue, String> {
+    match value.field("kind")?.as_str()? {
+        "String" => Ok(LiteralValue::String(symbol_from_json(value.field("value")?, interner)?)),
+        "Number" => Ok(LiteralValue::Number(value.field("value")?.as_f64()?)),
+        "Integer" => Ok(LiteralValue::Integer(value.field("value")?.as_f64()? as i64)),
+        "Char" => Ok(LiteralValue::Char(value.field("value")?.as_str()?.chars().next()
+            .ok_or("empty Char literal")?)),
+        "Boolean" => match value.field("value")? {
+            JsonValue::Bool(b) => Ok(LiteralValue::Boolean(*b)),
+            other => Err(format!("expected a bool, got {:?}", other)),
+        },
+        "None" => Ok(LiteralValue::None),
+        other => Err(format!("unknown LiteralValue kind {:?}", other)),
+    }
+}
+
+fn block_from_json(value: &JsonValue, interner: &mut Interner) -> Result<Vec<ASTNode>, String> {
+    value.as_array()?.iter().map(|item| node_from_json(item, interner)).collect()
+}
+
+fn handler_from_json(value: &JsonValue, interner: &mut Interner) -> Result<ExceptHandler, String> {
+    let code_filter = match value.field("code_filter")? {
+        v if v.is_null() => None,
+        v => Some(v.as_str()?.to_string()),
+    };
+    let binding = match value.field("binding")? {
+        v if v.is_null() => None,
+        v => Some(symbol_from_json(v, interner)?),
+    };
+    Ok(ExceptHandler { code_filter, binding, body: block_from_json(value.field("body")?, interner)? })
+}
+
+/// Reconstructs the `ASTNode` that `ast_to_json(node, interner)` produced,
+/// re-interning every name/string literal against `interner` as it goes —
+/// feed it the very `Interner` `node` was serialized with (as opposed to a
+/// fresh one) and identical source text re-resolves to identical `Symbol`s,
+/// so the result compares equal to `node` via `ASTNode`'s own `PartialEq`.
+fn node_from_json(value: &JsonValue, interner: &mut Interner) -> Result<ASTNode, String> {
+    let span = span_from_json(value.field("span")?)?;
+    match value.field("type")?.as_str()? {
+        "Program" => Ok(ASTNode::Program {
+            statements: block_from_json(value.field("statements")?, interner)?,
+            span,
+        }),
+        "Literal" => Ok(ASTNode::Literal {
+            value: literal_from_json(value.field("value")?, interner)?,
+            span,
+        }),
+        "Interpolation" => {
+            let parts = value.field("parts")?.as_array()?.iter().map(|part| {
+                match part.field("kind")?.as_str()? {
+                    "Literal" => Ok(InterpolationPart::Literal(symbol_from_json(part.field("value")?, interner)?)),
+                    "Expr" => Ok(InterpolationPart::Expr(Box::new(node_from_json(part.field("value")?, interner)?))),
+                    other => Err(format!("unknown InterpolationPart kind {:?}", other)),
+                }
+            }).collect::<Result<Vec<_>, String>>()?;
+            Ok(ASTNode::Interpolation { parts, span })
+        },
+        "Identifier" => Ok(ASTNode::Identifier {
+            name: symbol_from_json(value.field("name")?, interner)?,
+            span,
+        }),
+        "BinaryOp" => Ok(ASTNode::BinaryOp {
+            left: Box::new(node_from_json(value.field("left")?, interner)?),
+            operator: value.field("operator")?.as_str()?.to_string(),
+            right: Box::new(node_from_json(value.field("right")?, interner)?),
+            span,
+        }),
+        "UnaryOp" => Ok(ASTNode::UnaryOp {
+            operator: value.field("operator")?.as_str()?.to_string(),
+            operand: Box::new(node_from_json(value.field("operand")?, interner)?),
+            span,
+        }),
+        "Assignment" => Ok(ASTNode::Assignment {
+            name: symbol_from_json(value.field("name")?, interner)?,
+            value: Box::new(node_from_json(value.field("value")?, interner)?),
+            span,
+        }),
+        "FunctionCall" => Ok(ASTNode::FunctionCall {
+            name: symbol_from_json(value.field("name")?, interner)?,
+            arguments: block_from_json(value.field("arguments")?, interner)?,
+            span,
+            leading_trivia: Vec::new(),
+        }),
+        "IfStatement" => {
+            let elif_blocks = value.field("elif_blocks")?.as_array()?.iter().map(|elif| {
+                Ok((node_from_json(elif.field("condition")?, interner)?, block_from_json(elif.field("body")?, interner)?))
+            }).collect::<Result<Vec<_>, String>>()?;
+            let else_block = match value.field("else_block")? {
+                v if v.is_null() => None,
+                v => Some(block_from_json(v, interner)?),
+            };
+            Ok(ASTNode::IfStatement {
+                condition: Box::new(node_from_json(value.field("condition")?, interner)?),
+                then_block: block_from_json(value.field("then_block")?, interner)?,
+                elif_blocks,
+                else_block,
+                span,
+            })
+        },
+        "WhileLoop" => Ok(ASTNode::WhileLoop {
+            condition: Box::new(node_from_json(value.field("condition")?, interner)?),
+            body: block_from_json(value.field("body")?, interner)?,
+            span,
+        }),
+        "RepeatLoop" => Ok(ASTNode::RepeatLoop {
+            count: Box::new(node_from_json(value.field("count")?, interner)?),
+            body: block_from_json(value.field("body")?, interner)?,
+            span,
+        }),
+        "ForEachLoop" => Ok(ASTNode::ForEachLoop {
+            iterator: symbol_from_json(value.field("iterator")?, interner)?,
+            iterable: Box::new(node_from_json(value.field("iterable")?, interner)?),
+            body: block_from_json(value.field("body")?, interner)?,
+            span,
+        }),
+        "FunctionDef" => {
+            let parameters = value.field("parameters")?.as_array()?.iter()
+                .map(|p| symbol_from_json(p, interner))
+                .collect::<Result<Vec<_>, String>>()?;
+            Ok(ASTNode::FunctionDef {
+                name: symbol_from_json(value.field("name")?, interner)?,
+                parameters,
+                body: block_from_json(value.field("body")?, interner)?,
+                span,
+            })
+        },
+        "ReturnStatement" => {
+            let value_node = match value.field("value")? {
+                v if v.is_null() => None,
+                v => Some(Box::new(node_from_json(v, interner)?)),
+            };
+            Ok(ASTNode::ReturnStatement { value: value_node, span })
+        },
+        "BreakStatement" => Ok(ASTNode::BreakStatement { span }),
+        "ContinueStatement" => Ok(ASTNode::ContinueStatement { span }),
+        "ListNode" => Ok(ASTNode::ListNode {
+            elements: block_from_json(value.field("elements")?, interner)?,
+            span,
+            leading_trivia: Vec::new(),
+        }),
+        "DictNode" => {
+            let pairs = value.field("pairs")?.as_array()?.iter().map(|pair| {
+                Ok((node_from_json(pair.field("key")?, interner)?, node_from_json(pair.field("value")?, interner)?))
+            }).collect::<Result<Vec<_>, String>>()?;
+            Ok(ASTNode::DictNode { pairs, span })
+        },
+        "IndexAccess" => Ok(ASTNode::IndexAccess {
+            object: Box::new(node_from_json(value.field("object")?, interner)?),
+            index: Box::new(node_from_json(value.field("index")?, interner)?),
+            span,
+        }),
+        "IndexAssignment" => Ok(ASTNode::IndexAssignment {
+            object: Box::new(node_from_json(value.field("object")?, interner)?),
+            index: Box::new(node_from_json(value.field("index")?, interner)?),
+            value: Box::new(node_from_json(value.field("value")?, interner)?),
+            span,
+        }),
+        "TryExcept" => {
+            let handlers = value.field("handlers")?.as_array()?.iter()
+                .map(|h| handler_from_json(h, interner))
+                .collect::<Result<Vec<_>, String>>()?;
+            Ok(ASTNode::TryExcept {
+                try_block: block_from_json(value.field("try_block")?, interner)?,
+                handlers,
+                span,
+            })
+        },
+        "ExpressionStatement" => Ok(ASTNode::ExpressionStatement {
+            expression: Box::new(node_from_json(value.field("expression")?, interner)?),
+            span,
+        }),
+        other => Err(format!("unknown ASTNode type {:?}", other)),
+    }
+}
+
+/// The deserialize half of `ast_to_json`: parses `json` (as emitted by
+/// `ast_to_json`) back into an `ASTNode`, interning names and string
+/// literals against `interner` as they're read.
+pub fn ast_from_json(json: &str, interner: &mut Interner) -> Result<ASTNode, String> {
+    let value = JsonParser::new(json).parse_value()?;
+    node_from_json(&value, interner)
+}
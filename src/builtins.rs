@@ -0,0 +1,50 @@
+//! Data-driven call syntax for prefix-keyword builtins (`minuscule "hi"`,
+//! `aleatoire 1 a 10`, ...), consulted by `Parser::parse_primary`.
+//!
+//! Before this module, every one of these names needed its own
+//! `TokenType` plus a dedicated lexer arm and a dedicated parser match
+//! arm, so adding a builtin was a lexer+parser edit. Now the lexer always
+//! emits a plain `Identifier` for these names (same as any other word),
+//! and `parse_primary` looks the resolved name up here: if it's
+//! registered, it parses exactly the declared argument shape and emits
+//! an `ASTNode::FunctionCall`; otherwise the identifier falls through to
+//! normal variable-reference handling, completely unaware this table
+//! exists. Adding a new French builtin like `remplacer`/`joindre` is one
+//! row here (plus, of course, a `NativeRegistry::register` entry in
+//! `native.rs` so the VM knows how to run it).
+
+/// How a builtin's arguments are parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arity {
+    /// `n` plain expressions in a row, e.g. `minuscule "hi"` is `Fixed(1)`.
+    Fixed(usize),
+    /// Two expressions separated by the literal keyword `a`, as in
+    /// `aleatoire 1 a 10`.
+    InfixRange,
+}
+
+struct BuiltinSyntax {
+    name: &'static str,
+    arity: Arity,
+}
+
+/// The full table — add a row here to teach the parser a new
+/// prefix-keyword builtin without touching the lexer or any other arm.
+const BUILTINS: &[BuiltinSyntax] = &[
+    BuiltinSyntax { name: "minuscule", arity: Arity::Fixed(1) },
+    BuiltinSyntax { name: "majuscule", arity: Arity::Fixed(1) },
+    BuiltinSyntax { name: "longueur", arity: Arity::Fixed(1) },
+    BuiltinSyntax { name: "sqrt", arity: Arity::Fixed(1) },
+    BuiltinSyntax { name: "abs", arity: Arity::Fixed(1) },
+    BuiltinSyntax { name: "round", arity: Arity::Fixed(1) },
+    BuiltinSyntax { name: "floor", arity: Arity::Fixed(1) },
+    BuiltinSyntax { name: "ceil", arity: Arity::Fixed(1) },
+    BuiltinSyntax { name: "attendre", arity: Arity::Fixed(1) },
+    BuiltinSyntax { name: "aleatoire", arity: Arity::InfixRange },
+];
+
+/// Looks up `name`'s call syntax, if it's a registered prefix-keyword
+/// builtin.
+pub fn lookup(name: &str) -> Option<Arity> {
+    BUILTINS.iter().find(|b| b.name == name).map(|b| b.arity)
+}
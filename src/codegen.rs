@@ -0,0 +1,559 @@
+//! An ahead-of-time alternative to interpreting: instead of compiling the
+//! `Ast` to bytecode and running it on the VM (`Target::Interpret`, the
+//! normal path through `lib::run`), walk it once and emit source in
+//! another language — see `Target::Rust`/`Target::JavaScript` and
+//! `RunOptions::target` in `lib.rs`.
+//!
+//! Both emitters map MeowLang statements/expressions onto their closest
+//! target-language equivalent one-to-one rather than optimizing. A few
+//! constructs have no simple equivalent in either target —
+//! `dictionnaire(...)`, `essayer`/`sauf erreur`, and the pipeline builtins
+//! (`plier`, `lancer`, `|>`/`|:`/`|?`/`|&`) — and are emitted as a comment
+//! noting the gap rather than silently dropped.
+
+use crate::ast::{ASTNode, InterpolationPart, LiteralValue};
+use crate::interner::{Interner, Symbol};
+
+/// Which ahead-of-time language (if any) should be produced instead of
+/// interpreting directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    /// The normal path: compile to bytecode and run on the VM. Carries no
+    /// source to emit — callers should branch on this before calling
+    /// [`emit_source`].
+    Interpret,
+    Rust,
+    JavaScript,
+}
+
+impl Default for Target {
+    fn default() -> Self {
+        Target::Interpret
+    }
+}
+
+/// Emits `ast` as a standalone source file in `target`. Panics if `target`
+/// is `Target::Interpret`, which has nothing to emit — callers branch on
+/// `Target` before reaching here (see `lib::run`).
+pub fn emit_source(ast: &ASTNode, interner: &Interner, target: Target) -> String {
+    let statements = match ast {
+        ASTNode::Program { statements, .. } => statements,
+        _ => unreachable!("parser always produces ASTNode::Program at the root"),
+    };
+
+    match target {
+        Target::Interpret => unreachable!("Target::Interpret has no source to emit"),
+        Target::Rust => RustEmitter { interner }.emit_program(statements),
+        Target::JavaScript => JsEmitter { interner }.emit_program(statements),
+    }
+}
+
+/// Rewrites `name` so it's a legal identifier in either target: non
+/// alphanumeric/underscore characters (accented French letters included)
+/// become `_`, and a leading digit gets an `_` prefix.
+fn sanitize_ident(name: &str) -> String {
+    let mut out: String = name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+    if out.chars().next().map(|c| c.is_ascii_digit()).unwrap_or(true) {
+        out.insert(0, '_');
+    }
+    out
+}
+
+const RUST_PRELUDE: &str = r#"// --- MeowLang runtime prelude -------------------------------------------
+// MeowLang is dynamically typed; this enum and its helpers stand in for
+// the interpreter's own `Value` so the statement-by-statement translation
+// below doesn't need real type inference.
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+enum MValue {
+    Num(f64),
+    Int(i64),
+    Str(String),
+    Bool(bool),
+    List(Vec<MValue>),
+    None,
+}
+
+impl std::fmt::Display for MValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MValue::Num(n) => write!(f, "{}", n),
+            MValue::Int(i) => write!(f, "{}", i),
+            MValue::Str(s) => write!(f, "{}", s),
+            MValue::Bool(b) => write!(f, "{}", if *b { "vrai" } else { "faux" }),
+            MValue::List(items) => {
+                let strs: Vec<String> = items.iter().map(|v| v.to_string()).collect();
+                write!(f, "[{}]", strs.join(", "))
+            },
+            MValue::None => write!(f, ""),
+        }
+    }
+}
+
+#[allow(dead_code)]
+fn m_num(v: &MValue) -> f64 {
+    match v {
+        MValue::Num(n) => *n,
+        MValue::Int(i) => *i as f64,
+        MValue::Bool(b) => if *b { 1.0 } else { 0.0 },
+        _ => 0.0,
+    }
+}
+
+#[allow(dead_code)]
+fn m_truthy(v: &MValue) -> bool {
+    match v {
+        MValue::Bool(b) => *b,
+        MValue::Int(i) => *i != 0,
+        MValue::Num(n) => *n != 0.0,
+        MValue::Str(s) => !s.is_empty(),
+        MValue::List(items) => !items.is_empty(),
+        MValue::None => false,
+    }
+}
+
+#[allow(dead_code)]
+fn m_add(a: &MValue, b: &MValue) -> MValue {
+    match (a, b) {
+        (MValue::Str(x), MValue::Str(y)) => MValue::Str(format!("{}{}", x, y)),
+        (MValue::Int(x), MValue::Int(y)) => MValue::Int(x + y),
+        _ => MValue::Num(m_num(a) + m_num(b)),
+    }
+}
+#[allow(dead_code)]
+fn m_sub(a: &MValue, b: &MValue) -> MValue {
+    match (a, b) {
+        (MValue::Int(x), MValue::Int(y)) => MValue::Int(x - y),
+        _ => MValue::Num(m_num(a) - m_num(b)),
+    }
+}
+#[allow(dead_code)]
+fn m_mul(a: &MValue, b: &MValue) -> MValue {
+    match (a, b) {
+        (MValue::Int(x), MValue::Int(y)) => MValue::Int(x * y),
+        _ => MValue::Num(m_num(a) * m_num(b)),
+    }
+}
+#[allow(dead_code)]
+fn m_div(a: &MValue, b: &MValue) -> MValue { MValue::Num(m_num(a) / m_num(b)) }
+#[allow(dead_code)]
+fn m_rem(a: &MValue, b: &MValue) -> MValue {
+    match (a, b) {
+        (MValue::Int(x), MValue::Int(y)) => MValue::Int(x % y),
+        _ => MValue::Num(m_num(a) % m_num(b)),
+    }
+}
+#[allow(dead_code)]
+fn m_pow(a: &MValue, b: &MValue) -> MValue { MValue::Num(m_num(a).powf(m_num(b))) }
+#[allow(dead_code)]
+fn m_eq(a: &MValue, b: &MValue) -> bool {
+    match (a, b) {
+        (MValue::Str(x), MValue::Str(y)) => x == y,
+        (MValue::Bool(x), MValue::Bool(y)) => x == y,
+        (MValue::None, MValue::None) => true,
+        _ => m_num(a) == m_num(b),
+    }
+}
+#[allow(dead_code)]
+fn m_cmp(a: &MValue, b: &MValue) -> std::cmp::Ordering {
+    m_num(a).partial_cmp(&m_num(b)).unwrap_or(std::cmp::Ordering::Equal)
+}
+#[allow(dead_code)]
+fn m_ecrire(args: &[MValue]) {
+    let strs: Vec<String> = args.iter().map(|v| v.to_string()).collect();
+    println!("{}", strs.join(" "));
+}
+#[allow(dead_code)]
+fn m_longueur(v: &MValue) -> MValue {
+    match v {
+        MValue::Str(s) => MValue::Int(s.chars().count() as i64),
+        MValue::List(items) => MValue::Int(items.len() as i64),
+        _ => MValue::Int(0),
+    }
+}
+// --- end prelude ---------------------------------------------------------
+
+"#;
+
+struct RustEmitter<'a> {
+    interner: &'a Interner,
+}
+
+impl<'a> RustEmitter<'a> {
+    fn name(&self, sym: Symbol) -> String {
+        sanitize_ident(self.interner.resolve(sym))
+    }
+
+    fn emit_program(&self, statements: &[ASTNode]) -> String {
+        let mut funcs = String::new();
+        let mut main_body = String::new();
+        for stmt in statements {
+            if let ASTNode::FunctionDef { name, parameters, body, .. } = stmt {
+                funcs.push_str(&self.emit_function(*name, parameters, body));
+                funcs.push('\n');
+            } else {
+                self.emit_stmt(stmt, 1, &mut main_body);
+            }
+        }
+
+        let mut out = String::new();
+        out.push_str(RUST_PRELUDE);
+        out.push_str(&funcs);
+        out.push_str("fn main() {\n");
+        out.push_str(&main_body);
+        out.push_str("}\n");
+        out
+    }
+
+    fn emit_function(&self, name: Symbol, parameters: &[Symbol], body: &[ASTNode]) -> String {
+        let params: Vec<String> = parameters.iter().map(|p| format!("{}: MValue", self.name(*p))).collect();
+        let mut out = format!("fn {}({}) -> MValue {{\n", self.name(name), params.join(", "));
+        for stmt in body {
+            self.emit_stmt(stmt, 1, &mut out);
+        }
+        // Every MeowLang function implicitly returns `rien` if it falls off
+        // the end without a `retour` — see `VM::execute`'s doc comment.
+        out.push_str("    MValue::None\n}\n");
+        out
+    }
+
+    fn emit_block(&self, body: &[ASTNode], indent: usize, out: &mut String) {
+        for stmt in body {
+            self.emit_stmt(stmt, indent, out);
+        }
+    }
+
+    fn emit_stmt(&self, node: &ASTNode, indent: usize, out: &mut String) {
+        let pad = "    ".repeat(indent);
+        match node {
+            ASTNode::ExpressionStatement { expression, .. } => {
+                out.push_str(&format!("{}{};\n", pad, self.emit_expr(expression)));
+            },
+            ASTNode::Assignment { name, value, .. } => {
+                out.push_str(&format!("{}let mut {} = {};\n", pad, self.name(*name), self.emit_expr(value)));
+            },
+            ASTNode::IfStatement { condition, then_block, elif_blocks, else_block, .. } => {
+                out.push_str(&format!("{}if m_truthy(&{}) {{\n", pad, self.emit_expr(condition)));
+                self.emit_block(then_block, indent + 1, out);
+                out.push_str(&format!("{}}}", pad));
+                for (cond, block) in elif_blocks {
+                    out.push_str(&format!(" else if m_truthy(&{}) {{\n", self.emit_expr(cond)));
+                    self.emit_block(block, indent + 1, out);
+                    out.push_str(&format!("{}}}", pad));
+                }
+                if let Some(block) = else_block {
+                    out.push_str(" else {\n");
+                    self.emit_block(block, indent + 1, out);
+                    out.push_str(&format!("{}}}", pad));
+                }
+                out.push('\n');
+            },
+            ASTNode::WhileLoop { condition, body, .. } => {
+                out.push_str(&format!("{}while m_truthy(&{}) {{\n", pad, self.emit_expr(condition)));
+                self.emit_block(body, indent + 1, out);
+                out.push_str(&format!("{}}}\n", pad));
+            },
+            ASTNode::RepeatLoop { count, body, .. } => {
+                out.push_str(&format!("{}for _ in 0..(m_num(&{}) as i64) {{\n", pad, self.emit_expr(count)));
+                self.emit_block(body, indent + 1, out);
+                out.push_str(&format!("{}}}\n", pad));
+            },
+            ASTNode::ForEachLoop { iterator, iterable, body, .. } => {
+                out.push_str(&format!("{}if let MValue::List(__items) = {} {{\n", pad, self.emit_expr(iterable)));
+                out.push_str(&format!("{}    for {} in __items {{\n", pad, self.name(*iterator)));
+                self.emit_block(body, indent + 2, out);
+                out.push_str(&format!("{}    }}\n{}}}\n", pad, pad));
+            },
+            ASTNode::ReturnStatement { value, .. } => {
+                let v = value.as_ref().map(|v| self.emit_expr(v)).unwrap_or_else(|| "MValue::None".to_string());
+                out.push_str(&format!("{}return {};\n", pad, v));
+            },
+            ASTNode::BreakStatement { .. } => out.push_str(&format!("{}break;\n", pad)),
+            ASTNode::ContinueStatement { .. } => out.push_str(&format!("{}continue;\n", pad)),
+            ASTNode::FunctionDef { .. } => {
+                out.push_str(&format!("{}// nested function definitions aren't supported by this transpiler; hoist to top level\n", pad));
+            },
+            ASTNode::DictNode { .. } | ASTNode::IndexAssignment { .. } | ASTNode::TryExcept { .. } => {
+                out.push_str(&format!("{}// unsupported construct skipped: no Rust-target equivalent yet\n", pad));
+            },
+            _ => out.push_str(&format!("{}{};\n", pad, self.emit_expr(node))),
+        }
+    }
+
+    fn emit_expr(&self, node: &ASTNode) -> String {
+        match node {
+            ASTNode::Literal { value, .. } => self.emit_literal(value),
+            ASTNode::Identifier { name, .. } => self.name(*name),
+            ASTNode::BinaryOp { left, operator, right, .. } => {
+                let l = self.emit_expr(left);
+                let r = self.emit_expr(right);
+                match operator.as_str() {
+                    "+" => format!("m_add(&{}, &{})", l, r),
+                    "-" => format!("m_sub(&{}, &{})", l, r),
+                    "*" => format!("m_mul(&{}, &{})", l, r),
+                    "/" => format!("m_div(&{}, &{})", l, r),
+                    "%" => format!("m_rem(&{}, &{})", l, r),
+                    "**" => format!("m_pow(&{}, &{})", l, r),
+                    "=" => format!("MValue::Bool(m_eq(&{}, &{}))", l, r),
+                    "!=" => format!("MValue::Bool(!m_eq(&{}, &{}))", l, r),
+                    "<" => format!("MValue::Bool(m_cmp(&{}, &{}) == std::cmp::Ordering::Less)", l, r),
+                    "<=" => format!("MValue::Bool(m_cmp(&{}, &{}) != std::cmp::Ordering::Greater)", l, r),
+                    ">" => format!("MValue::Bool(m_cmp(&{}, &{}) == std::cmp::Ordering::Greater)", l, r),
+                    ">=" => format!("MValue::Bool(m_cmp(&{}, &{}) != std::cmp::Ordering::Less)", l, r),
+                    "et" => format!("MValue::Bool(m_truthy(&{}) && m_truthy(&{}))", l, r),
+                    "ou" => format!("MValue::Bool(m_truthy(&{}) || m_truthy(&{}))", l, r),
+                    other => format!("/* unsupported operator `{}` */ MValue::None", other),
+                }
+            },
+            ASTNode::UnaryOp { operator, operand, .. } => {
+                let v = self.emit_expr(operand);
+                match operator.as_str() {
+                    "-" => format!("m_sub(&MValue::Int(0), &{})", v),
+                    "non" => format!("MValue::Bool(!m_truthy(&{}))", v),
+                    other => format!("/* unsupported operator `{}` */ MValue::None", other),
+                }
+            },
+            ASTNode::FunctionCall { name, arguments, .. } => self.emit_call(*name, arguments),
+            ASTNode::ListNode { elements, .. } => {
+                let items: Vec<String> = elements.iter().map(|e| self.emit_expr(e)).collect();
+                format!("MValue::List(vec![{}])", items.join(", "))
+            },
+            ASTNode::IndexAccess { object, index, .. } => {
+                format!(
+                    "{{ let __l = {}; let __i = m_num(&{}) as usize; if let MValue::List(v) = &__l {{ v[__i].clone() }} else {{ MValue::None }} }}",
+                    self.emit_expr(object), self.emit_expr(index),
+                )
+            },
+            ASTNode::Interpolation { parts, .. } => {
+                let mut fmt_string = String::new();
+                let mut args = Vec::new();
+                for part in parts {
+                    match part {
+                        InterpolationPart::Literal(s) => {
+                            let text = self.interner.resolve(*s);
+                            fmt_string.push_str(&text.replace('{', "{{").replace('}', "}}"));
+                        },
+                        InterpolationPart::Expr(expr) => {
+                            fmt_string.push_str("{}");
+                            args.push(self.emit_expr(expr));
+                        },
+                    }
+                }
+                if args.is_empty() {
+                    format!("MValue::Str({:?}.to_string())", fmt_string)
+                } else {
+                    format!("MValue::Str(format!({:?}, {}))", fmt_string, args.join(", "))
+                }
+            },
+            ASTNode::DictNode { .. } | ASTNode::IndexAssignment { .. } | ASTNode::TryExcept { .. } => {
+                "/* unsupported: no Rust-target equivalent yet */ MValue::None".to_string()
+            },
+            _ => "MValue::None".to_string(),
+        }
+    }
+
+    fn emit_literal(&self, value: &LiteralValue) -> String {
+        match value {
+            LiteralValue::String(sym) => format!("MValue::Str({:?}.to_string())", self.interner.resolve(*sym)),
+            LiteralValue::Number(n) => format!("MValue::Num({:?})", n),
+            LiteralValue::Integer(i) => format!("MValue::Int({})", i),
+            LiteralValue::Char(c) => format!("MValue::Str({:?}.to_string())", c.to_string()),
+            LiteralValue::Boolean(b) => format!("MValue::Bool({})", b),
+            LiteralValue::None => "MValue::None".to_string(),
+        }
+    }
+
+    fn emit_call(&self, name: Symbol, arguments: &[ASTNode]) -> String {
+        let name_str = self.interner.resolve(name);
+        let args: Vec<String> = arguments.iter().map(|a| self.emit_expr(a)).collect();
+        let first = || args.first().cloned().unwrap_or_else(|| "MValue::Num(0.0)".to_string());
+        match name_str {
+            "ecrire" => format!("m_ecrire(&[{}])", args.join(", ")),
+            "longueur" => format!("m_longueur(&{})", first()),
+            "abs" => format!("MValue::Num(m_num(&{}).abs())", first()),
+            "sqrt" => format!("MValue::Num(m_num(&{}).sqrt())", first()),
+            "round" => format!("MValue::Int(m_num(&{}).round() as i64)", first()),
+            "floor" => format!("MValue::Int(m_num(&{}).floor() as i64)", first()),
+            "ceil" => format!("MValue::Int(m_num(&{}).ceil() as i64)", first()),
+            // Everything else (the random/string/time builtins, plier,
+            // lancer) is emitted as a plain call to a same-named Rust
+            // function the user is expected to supply — there's no
+            // tree-walking translation for them.
+            _ => format!("{}({})", sanitize_ident(name_str), args.join(", ")),
+        }
+    }
+}
+
+struct JsEmitter<'a> {
+    interner: &'a Interner,
+}
+
+impl<'a> JsEmitter<'a> {
+    fn name(&self, sym: Symbol) -> String {
+        sanitize_ident(self.interner.resolve(sym))
+    }
+
+    fn emit_program(&self, statements: &[ASTNode]) -> String {
+        let mut out = String::new();
+        out.push_str("// --- transpiled from MeowLang ---\n// MeowLang is already dynamically typed like JavaScript, so this target\n// needs no runtime prelude beyond mapping `ecrire` to `console.log`.\n\n");
+        for stmt in statements {
+            self.emit_stmt(stmt, 0, &mut out);
+        }
+        out
+    }
+
+    fn emit_block(&self, body: &[ASTNode], indent: usize, out: &mut String) {
+        for stmt in body {
+            self.emit_stmt(stmt, indent, out);
+        }
+    }
+
+    fn emit_stmt(&self, node: &ASTNode, indent: usize, out: &mut String) {
+        let pad = "  ".repeat(indent);
+        match node {
+            ASTNode::ExpressionStatement { expression, .. } => {
+                out.push_str(&format!("{}{};\n", pad, self.emit_expr(expression)));
+            },
+            ASTNode::Assignment { name, value, .. } => {
+                out.push_str(&format!("{}let {} = {};\n", pad, self.name(*name), self.emit_expr(value)));
+            },
+            ASTNode::IfStatement { condition, then_block, elif_blocks, else_block, .. } => {
+                out.push_str(&format!("{}if ({}) {{\n", pad, self.emit_expr(condition)));
+                self.emit_block(then_block, indent + 1, out);
+                out.push_str(&format!("{}}}", pad));
+                for (cond, block) in elif_blocks {
+                    out.push_str(&format!(" else if ({}) {{\n", self.emit_expr(cond)));
+                    self.emit_block(block, indent + 1, out);
+                    out.push_str(&format!("{}}}", pad));
+                }
+                if let Some(block) = else_block {
+                    out.push_str(" else {\n");
+                    self.emit_block(block, indent + 1, out);
+                    out.push_str(&format!("{}}}", pad));
+                }
+                out.push('\n');
+            },
+            ASTNode::WhileLoop { condition, body, .. } => {
+                out.push_str(&format!("{}while ({}) {{\n", pad, self.emit_expr(condition)));
+                self.emit_block(body, indent + 1, out);
+                out.push_str(&format!("{}}}\n", pad));
+            },
+            ASTNode::RepeatLoop { count, body, .. } => {
+                out.push_str(&format!("{}for (let __i = 0; __i < ({}); __i++) {{\n", pad, self.emit_expr(count)));
+                self.emit_block(body, indent + 1, out);
+                out.push_str(&format!("{}}}\n", pad));
+            },
+            ASTNode::ForEachLoop { iterator, iterable, body, .. } => {
+                out.push_str(&format!("{}for (const {} of {}) {{\n", pad, self.name(*iterator), self.emit_expr(iterable)));
+                self.emit_block(body, indent + 1, out);
+                out.push_str(&format!("{}}}\n", pad));
+            },
+            ASTNode::FunctionDef { name, parameters, body, .. } => {
+                let params: Vec<String> = parameters.iter().map(|p| self.name(*p)).collect();
+                out.push_str(&format!("{}function {}({}) {{\n", pad, self.name(*name), params.join(", ")));
+                self.emit_block(body, indent + 1, out);
+                out.push_str(&format!("{}}}\n", pad));
+            },
+            ASTNode::ReturnStatement { value, .. } => {
+                let v = value.as_ref().map(|v| self.emit_expr(v)).unwrap_or_default();
+                out.push_str(&format!("{}return {};\n", pad, v));
+            },
+            ASTNode::BreakStatement { .. } => out.push_str(&format!("{}break;\n", pad)),
+            ASTNode::ContinueStatement { .. } => out.push_str(&format!("{}continue;\n", pad)),
+            ASTNode::DictNode { .. } | ASTNode::IndexAssignment { .. } | ASTNode::TryExcept { .. } => {
+                out.push_str(&format!("{}// unsupported construct skipped: no JavaScript-target equivalent yet\n", pad));
+            },
+            _ => out.push_str(&format!("{}{};\n", pad, self.emit_expr(node))),
+        }
+    }
+
+    fn emit_expr(&self, node: &ASTNode) -> String {
+        match node {
+            ASTNode::Literal { value, .. } => self.emit_literal(value),
+            ASTNode::Identifier { name, .. } => self.name(*name),
+            ASTNode::BinaryOp { left, operator, right, .. } => {
+                let l = self.emit_expr(left);
+                let r = self.emit_expr(right);
+                if operator == "**" {
+                    return format!("Math.pow({}, {})", l, r);
+                }
+                let op = match operator.as_str() {
+                    "=" => "===",
+                    "et" => "&&",
+                    "ou" => "||",
+                    other => other,
+                };
+                format!("({} {} {})", l, op, r)
+            },
+            ASTNode::UnaryOp { operator, operand, .. } => {
+                let v = self.emit_expr(operand);
+                match operator.as_str() {
+                    "-" => format!("(-{})", v),
+                    "non" => format!("(!{})", v),
+                    other => format!("/* unsupported operator `{}` */ null", other),
+                }
+            },
+            ASTNode::FunctionCall { name, arguments, .. } => self.emit_call(*name, arguments),
+            ASTNode::ListNode { elements, .. } => {
+                let items: Vec<String> = elements.iter().map(|e| self.emit_expr(e)).collect();
+                format!("[{}]", items.join(", "))
+            },
+            ASTNode::IndexAccess { object, index, .. } => {
+                format!("{}[{}]", self.emit_expr(object), self.emit_expr(index))
+            },
+            ASTNode::Interpolation { parts, .. } => {
+                let mut out = String::from("`");
+                for part in parts {
+                    match part {
+                        InterpolationPart::Literal(s) => {
+                            let text = self.interner.resolve(*s);
+                            out.push_str(&text.replace('\\', "\\\\").replace('`', "\\`").replace('$', "\\$"));
+                        },
+                        InterpolationPart::Expr(expr) => {
+                            out.push_str("${");
+                            out.push_str(&self.emit_expr(expr));
+                            out.push('}');
+                        },
+                    }
+                }
+                out.push('`');
+                out
+            },
+            ASTNode::DictNode { .. } | ASTNode::IndexAssignment { .. } | ASTNode::TryExcept { .. } => {
+                "/* unsupported: no JavaScript-target equivalent yet */ null".to_string()
+            },
+            _ => "null".to_string(),
+        }
+    }
+
+    fn emit_literal(&self, value: &LiteralValue) -> String {
+        match value {
+            LiteralValue::String(sym) => format!("{:?}", self.interner.resolve(*sym)),
+            LiteralValue::Number(n) => format!("{}", n),
+            LiteralValue::Integer(i) => format!("{}", i),
+            LiteralValue::Char(c) => format!("{:?}", c.to_string()),
+            LiteralValue::Boolean(b) => b.to_string(),
+            LiteralValue::None => "null".to_string(),
+        }
+    }
+
+    fn emit_call(&self, name: Symbol, arguments: &[ASTNode]) -> String {
+        let name_str = self.interner.resolve(name);
+        let args: Vec<String> = arguments.iter().map(|a| self.emit_expr(a)).collect();
+        let first = || args.first().cloned().unwrap_or_default();
+        match name_str {
+            "ecrire" => format!("console.log({})", args.join(", ")),
+            "longueur" => format!("({}).length", first()),
+            "minuscule" => format!("({}).toLowerCase()", first()),
+            "majuscule" => format!("({}).toUpperCase()", first()),
+            "abs" => format!("Math.abs({})", first()),
+            "sqrt" => format!("Math.sqrt({})", first()),
+            "round" => format!("Math.round({})", first()),
+            "floor" => format!("Math.floor({})", first()),
+            "ceil" => format!("Math.ceil({})", first()),
+            _ => format!("{}({})", sanitize_ident(name_str), args.join(", ")),
+        }
+    }
+}
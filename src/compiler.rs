@@ -0,0 +1,726 @@
+use std::collections::HashMap;
+
+use crate::ast::{ASTNode, InterpolationPart, LiteralValue, Position};
+use crate::interner::{Interner, Symbol};
+use crate::vm::Value;
+
+/// The comparison performed by `Instr::Cmp`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CmpOp {
+    Eq,
+    NotEq,
+    Lt,
+    Gt,
+    LessEq,
+    GreaterEq,
+}
+
+/// A single bytecode instruction. Variable slots are resolved to `u16`
+/// indices at compile time, so the VM does array indexing into a locals
+/// slab instead of `HashMap::get` on every read/write. `Call` carries the
+/// callee's interned name rather than a numeric id, since builtins are
+/// dispatched by name and user functions live in a name-keyed table — see
+/// `CompiledProgram::function_index`.
+#[derive(Debug, Clone)]
+pub enum Instr {
+    PushConst(usize),
+    LoadVar(u16),
+    StoreVar(u16),
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    Pow,
+    Neg,
+    Not,
+    And,
+    Or,
+    Cmp(CmpOp),
+    Jump(usize),
+    JumpIfFalse(usize),
+    Call(Symbol, usize),
+    Return,
+    MakeList(usize),
+    /// Pops `count` key/value pairs (2 * `count` stack values, key below
+    /// value within each pair) and builds a `Value::Dict`; non-string keys
+    /// are coerced via `to_string` the same way `add_values` coerces
+    /// operands when concatenating onto a string.
+    MakeDict(usize),
+    Index,
+    /// `objet[index] = valeur`: pops `index`, the container, then the
+    /// value, mutates a clone of the container, and pushes the clone back
+    /// for the caller to write into wherever the container came from — see
+    /// `Unit::compile_store_into`.
+    StoreIndex,
+    /// `|>`: pop the left value, call the named function with it as the
+    /// sole argument, push the result.
+    PipeApply(Symbol),
+    /// `|:`: pop a `Value::List`, call the named function on each
+    /// element, push the resulting list.
+    PipeMap(Symbol),
+    /// `|?`: pop a `Value::List`, keep elements where the named function
+    /// returns a truthy `Value`, push the resulting list.
+    PipeFilter(Symbol),
+    /// `|&`: pop two `Value::List`s, push their concatenation.
+    Concat,
+    /// `plier`/fold: pop a `Value::List` and an initial accumulator, call
+    /// the named two-argument function as `f(accumulateur, element)` for
+    /// every element in order, push the final accumulator.
+    Fold(Symbol),
+    /// `lancer(nom_fonction, args...)`: pops `argc` arguments, runs the
+    /// named function to completion (there is no real concurrent scheduler
+    /// underneath — see `VM::tasks`), records its result under a fresh
+    /// task handle, and pushes that handle as a `Value::Integer`.
+    Spawn(Symbol, usize),
+    /// Begins a guarded region: on a runtime error, the VM unwinds the
+    /// operand stack back to this point and looks for the first handler
+    /// whose `code_filter` matches, pushing the caught error as a
+    /// `Value::Dict` and jumping to that handler's `pc`. A handler with no
+    /// matching filter re-propagates the error to the next outer region
+    /// instead of swallowing it.
+    TryStart(Vec<HandlerSpec>),
+    TryEnd,
+    Pop,
+    /// Interpolated string literal: pops `count` values (pushed in source
+    /// order), coerces each to text with `Value::to_string` — the same
+    /// coercion `add_values` uses for string concatenation — and pushes
+    /// their join as a single `Value::String`.
+    Interpolate(usize),
+}
+
+/// One `sauf erreur` clause, resolved to bytecode: `pc` is where its body
+/// starts, right after the VM has pushed the caught error onto the stack.
+#[derive(Debug, Clone)]
+pub struct HandlerSpec {
+    pub code_filter: Option<String>,
+    pub pc: usize,
+}
+
+/// A user-defined function lowered to its own flat bytecode chunk with its
+/// own locals slab. Functions in MeowLang don't close over the caller's
+/// variables (the old tree-walker swapped `self.variables` wholesale per
+/// call), so each function compiles against a fresh `SlotTable`.
+#[derive(Debug, Clone)]
+pub struct CompiledFunction {
+    pub param_count: usize,
+    pub frame_size: usize,
+    pub code: Vec<Instr>,
+    pub positions: Vec<Position>,
+    /// Slot index -> source identifier, for diagnostics. See `SlotTable`.
+    pub slot_names: Vec<Symbol>,
+}
+
+/// The output of compiling a whole program: the top-level chunk plus every
+/// function it defines, hoisted so a function can be called from code that
+/// textually precedes its definition.
+pub struct CompiledProgram {
+    pub code: Vec<Instr>,
+    pub positions: Vec<Position>,
+    pub frame_size: usize,
+    pub consts: Vec<Value>,
+    pub functions: Vec<CompiledFunction>,
+    pub function_index: HashMap<Symbol, usize>,
+    pub slot_names: Vec<Symbol>,
+}
+
+/// Assigns a stable slot index to every local variable name seen in a
+/// single function/program body. `names` mirrors `slots` in insertion
+/// order so the VM can recover a variable's source name for diagnostics
+/// (e.g. "variable 'x' non définie") without interning them again.
+#[derive(Default)]
+struct SlotTable {
+    slots: HashMap<Symbol, u16>,
+    names: Vec<Symbol>,
+}
+
+impl SlotTable {
+    fn get_or_create(&mut self, name: Symbol) -> u16 {
+        if let Some(&slot) = self.slots.get(&name) {
+            return slot;
+        }
+        let slot = self.names.len() as u16;
+        self.slots.insert(name, slot);
+        self.names.push(name);
+        slot
+    }
+
+    fn len(&self) -> usize {
+        self.names.len()
+    }
+}
+
+pub struct Compiler<'a> {
+    interner: &'a mut Interner,
+    consts: Vec<Value>,
+    functions: Vec<CompiledFunction>,
+    function_index: HashMap<Symbol, usize>,
+    /// Bumped for every loop, to name the hidden bound/index locals a
+    /// `repeter`/`pour chaque` loop needs without colliding across loops.
+    synthetic_counter: usize,
+}
+
+/// The `casser`/`continuer` jumps still waiting to be patched for the loop
+/// currently being compiled, innermost last.
+#[derive(Default)]
+struct LoopFrame {
+    break_jumps: Vec<usize>,
+    continue_jumps: Vec<usize>,
+    /// `Unit::try_depth` at the point this loop started — subtracted from
+    /// the depth at a `casser`/`continuer` site to know how many enclosing
+    /// `essayer` blocks (opened inside this loop's own body) the jump out
+    /// needs to unwind with `Instr::TryEnd` before it, so a jump out of
+    /// `essayer { ... casser ... }` doesn't leave a stale `TryRegion` on
+    /// the VM's `try_stack`.
+    try_depth_at_entry: usize,
+}
+
+/// One function/program body being lowered: its own slot table, code
+/// buffer, and parallel position table.
+struct Unit<'a, 'b> {
+    compiler: &'a mut Compiler<'b>,
+    slots: SlotTable,
+    code: Vec<Instr>,
+    positions: Vec<Position>,
+    loop_stack: Vec<LoopFrame>,
+    /// Number of `essayer` blocks currently open around the statement
+    /// being compiled — see `LoopFrame::try_depth_at_entry`.
+    try_depth: usize,
+}
+
+impl<'a> Compiler<'a> {
+    pub fn new(interner: &'a mut Interner) -> Self {
+        Compiler {
+            interner,
+            consts: Vec::new(),
+            functions: Vec::new(),
+            function_index: HashMap::new(),
+            synthetic_counter: 0,
+        }
+    }
+
+    pub fn compile(mut self, program: &ASTNode) -> CompiledProgram {
+        let statements = match program {
+            ASTNode::Program { statements, .. } => statements,
+            _ => unreachable!("parser always produces ASTNode::Program at the root"),
+        };
+
+        self.hoist_functions(statements);
+
+        let (code, positions, frame_size, slot_names) = {
+            let mut unit = Unit {
+                compiler: &mut self,
+                slots: SlotTable::default(),
+                code: Vec::new(),
+                positions: Vec::new(),
+                loop_stack: Vec::new(),
+                try_depth: 0,
+            };
+            unit.compile_block(statements);
+            let frame_size = unit.slots.len();
+            let slot_names = unit.slots.names.clone();
+            (unit.code, unit.positions, frame_size, slot_names)
+        };
+
+        CompiledProgram {
+            code,
+            positions,
+            frame_size,
+            consts: self.consts,
+            functions: self.functions,
+            function_index: self.function_index,
+            slot_names,
+        }
+    }
+
+    /// Registers every `FunctionDef` so it's callable regardless of where
+    /// the call site sits relative to the definition in source order.
+    fn hoist_functions(&mut self, statements: &[ASTNode]) {
+        for stmt in statements {
+            match stmt {
+                ASTNode::FunctionDef { name, parameters, body, .. } => {
+                    let mut unit = Unit {
+                        compiler: self,
+                        slots: SlotTable::default(),
+                        code: Vec::new(),
+                        positions: Vec::new(),
+                        loop_stack: Vec::new(),
+                        try_depth: 0,
+                    };
+                    for param in parameters {
+                        unit.slots.get_or_create(*param);
+                    }
+                    unit.compile_block(body);
+                    let frame_size = unit.slots.len();
+                    let slot_names = unit.slots.names.clone();
+                    let (code, positions) = (unit.code, unit.positions);
+
+                    let func_idx = self.functions.len();
+                    self.functions.push(CompiledFunction {
+                        param_count: parameters.len(),
+                        frame_size,
+                        code,
+                        positions,
+                        slot_names,
+                    });
+                    self.function_index.insert(*name, func_idx);
+                },
+                ASTNode::IfStatement { then_block, elif_blocks, else_block, .. } => {
+                    self.hoist_functions(then_block);
+                    for (_, body) in elif_blocks {
+                        self.hoist_functions(body);
+                    }
+                    if let Some(body) = else_block {
+                        self.hoist_functions(body);
+                    }
+                },
+                ASTNode::WhileLoop { body, .. }
+                | ASTNode::RepeatLoop { body, .. }
+                | ASTNode::ForEachLoop { body, .. } => self.hoist_functions(body),
+                ASTNode::TryExcept { try_block, handlers, .. } => {
+                    self.hoist_functions(try_block);
+                    for handler in handlers {
+                        self.hoist_functions(&handler.body);
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    fn push_const(&mut self, value: Value) -> usize {
+        self.consts.push(value);
+        self.consts.len() - 1
+    }
+
+    fn synthetic_name(&mut self, prefix: &str) -> Symbol {
+        let id = self.synthetic_counter;
+        self.synthetic_counter += 1;
+        self.interner.intern(&format!("__{}{}", prefix, id))
+    }
+}
+
+impl<'a, 'b> Unit<'a, 'b> {
+    fn emit(&mut self, instr: Instr, position: &Position) -> usize {
+        self.code.push(instr);
+        self.positions.push(position.clone());
+        self.code.len() - 1
+    }
+
+    fn patch_jump(&mut self, idx: usize, target: usize) {
+        self.code[idx] = match &self.code[idx] {
+            Instr::Jump(_) => Instr::Jump(target),
+            Instr::JumpIfFalse(_) => Instr::JumpIfFalse(target),
+            _ => unreachable!("patch_jump called on a non-jump instruction"),
+        };
+    }
+
+    fn here(&self) -> usize {
+        self.code.len()
+    }
+
+    /// Patches every jump in `jumps` to `target` — used to resolve a
+    /// loop's collected `casser`/`continuer` sites once the loop's exit
+    /// point or increment step is known.
+    fn patch_all(&mut self, jumps: Vec<usize>, target: usize) {
+        for idx in jumps {
+            self.patch_jump(idx, target);
+        }
+    }
+
+    /// Emits one `Instr::TryEnd` for every `essayer` block opened since the
+    /// innermost enclosing loop started — a `casser`/`continuer` jumps
+    /// straight out of the loop body (and past the normal `TryEnd` at the
+    /// bottom of any `essayer` it's nested in), so without this the VM's
+    /// `Frame::try_stack` would keep a stale `TryRegion` around, ready to
+    /// catch a later, unrelated error at the wrong stack depth.
+    fn unwind_try_regions_for_loop_exit(&mut self, position: &Position) {
+        let Some(loop_frame) = self.loop_stack.last() else {
+            return;
+        };
+        let open = self.try_depth - loop_frame.try_depth_at_entry;
+        for _ in 0..open {
+            self.emit(Instr::TryEnd, position);
+        }
+    }
+
+    fn compile_block(&mut self, statements: &[ASTNode]) {
+        for stmt in statements {
+            self.compile_statement(stmt);
+        }
+    }
+
+    fn compile_statement(&mut self, node: &ASTNode) {
+        match node {
+            ASTNode::ExpressionStatement { expression, .. } => {
+                self.compile_expr(expression);
+                self.emit(Instr::Pop, node.position());
+            },
+            ASTNode::Assignment { name, value, span } => {
+                self.compile_expr(value);
+                let slot = self.slots.get_or_create(*name);
+                self.emit(Instr::StoreVar(slot), &span.start);
+            },
+            ASTNode::IfStatement { condition, then_block, elif_blocks, else_block, span } => {
+                self.compile_expr(condition);
+                let mut end_jumps = Vec::new();
+                let jump_to_next = self.emit(Instr::JumpIfFalse(0), &span.start);
+                self.compile_block(then_block);
+                end_jumps.push(self.emit(Instr::Jump(0), &span.start));
+                self.patch_jump(jump_to_next, self.here());
+
+                for (elif_condition, elif_body) in elif_blocks {
+                    self.compile_expr(elif_condition);
+                    let jump_to_next = self.emit(Instr::JumpIfFalse(0), &span.start);
+                    self.compile_block(elif_body);
+                    end_jumps.push(self.emit(Instr::Jump(0), &span.start));
+                    self.patch_jump(jump_to_next, self.here());
+                }
+
+                if let Some(body) = else_block {
+                    self.compile_block(body);
+                }
+
+                let end = self.here();
+                for idx in end_jumps {
+                    self.patch_jump(idx, end);
+                }
+            },
+            ASTNode::WhileLoop { condition, body, span } => {
+                let loop_start = self.here();
+                self.compile_expr(condition);
+                let exit_jump = self.emit(Instr::JumpIfFalse(0), &span.start);
+
+                self.loop_stack.push(LoopFrame { try_depth_at_entry: self.try_depth, ..LoopFrame::default() });
+                self.compile_block(body);
+                let loop_frame = self.loop_stack.pop().unwrap();
+                let continue_target = self.here();
+                self.patch_all(loop_frame.continue_jumps, continue_target);
+
+                self.emit(Instr::Jump(loop_start), &span.start);
+                let end = self.here();
+                self.patch_jump(exit_jump, end);
+                self.patch_all(loop_frame.break_jumps, end);
+            },
+            ASTNode::RepeatLoop { count, body, span } => {
+                self.compile_expr(count);
+                let bound_name = self.compiler.synthetic_name("repeat_bound");
+                let bound_slot = self.slots.get_or_create(bound_name);
+                self.emit(Instr::StoreVar(bound_slot), &span.start);
+
+                let compteur = self.compiler.interner.intern("compteur");
+                let compteur_slot = self.slots.get_or_create(compteur);
+                let one = self.compiler.push_const(Value::Integer(1));
+                self.emit(Instr::PushConst(one), &span.start);
+                self.emit(Instr::StoreVar(compteur_slot), &span.start);
+
+                let loop_start = self.here();
+                self.emit(Instr::LoadVar(compteur_slot), &span.start);
+                self.emit(Instr::LoadVar(bound_slot), &span.start);
+                self.emit(Instr::Cmp(CmpOp::LessEq), &span.start);
+                let exit_jump = self.emit(Instr::JumpIfFalse(0), &span.start);
+
+                self.loop_stack.push(LoopFrame { try_depth_at_entry: self.try_depth, ..LoopFrame::default() });
+                self.compile_block(body);
+                let loop_frame = self.loop_stack.pop().unwrap();
+                let continue_target = self.here();
+                self.patch_all(loop_frame.continue_jumps, continue_target);
+
+                self.emit(Instr::LoadVar(compteur_slot), &span.start);
+                let one = self.compiler.push_const(Value::Integer(1));
+                self.emit(Instr::PushConst(one), &span.start);
+                self.emit(Instr::Add, &span.start);
+                self.emit(Instr::StoreVar(compteur_slot), &span.start);
+                self.emit(Instr::Jump(loop_start), &span.start);
+                let end = self.here();
+                self.patch_jump(exit_jump, end);
+                self.patch_all(loop_frame.break_jumps, end);
+            },
+            ASTNode::ForEachLoop { iterator, iterable, body, span } => {
+                self.compile_expr(iterable);
+                let list_name = self.compiler.synthetic_name("foreach_list");
+                let list_slot = self.slots.get_or_create(list_name);
+                self.emit(Instr::StoreVar(list_slot), &span.start);
+
+                self.emit(Instr::LoadVar(list_slot), &span.start);
+                let longueur = self.compiler.interner.intern("longueur");
+                self.emit(Instr::Call(longueur, 1), &span.start);
+                let len_name = self.compiler.synthetic_name("foreach_len");
+                let len_slot = self.slots.get_or_create(len_name);
+                self.emit(Instr::StoreVar(len_slot), &span.start);
+
+                let zero = self.compiler.push_const(Value::Integer(0));
+                self.emit(Instr::PushConst(zero), &span.start);
+                let idx_name = self.compiler.synthetic_name("foreach_idx");
+                let idx_slot = self.slots.get_or_create(idx_name);
+                self.emit(Instr::StoreVar(idx_slot), &span.start);
+
+                let loop_start = self.here();
+                self.emit(Instr::LoadVar(idx_slot), &span.start);
+                self.emit(Instr::LoadVar(len_slot), &span.start);
+                self.emit(Instr::Cmp(CmpOp::Lt), &span.start);
+                let exit_jump = self.emit(Instr::JumpIfFalse(0), &span.start);
+
+                self.emit(Instr::LoadVar(list_slot), &span.start);
+                self.emit(Instr::LoadVar(idx_slot), &span.start);
+                self.emit(Instr::Index, &span.start);
+                let iterator_slot = self.slots.get_or_create(*iterator);
+                self.emit(Instr::StoreVar(iterator_slot), &span.start);
+
+                self.loop_stack.push(LoopFrame { try_depth_at_entry: self.try_depth, ..LoopFrame::default() });
+                self.compile_block(body);
+                let loop_frame = self.loop_stack.pop().unwrap();
+                let continue_target = self.here();
+                self.patch_all(loop_frame.continue_jumps, continue_target);
+
+                self.emit(Instr::LoadVar(idx_slot), &span.start);
+                let one = self.compiler.push_const(Value::Integer(1));
+                self.emit(Instr::PushConst(one), &span.start);
+                self.emit(Instr::Add, &span.start);
+                self.emit(Instr::StoreVar(idx_slot), &span.start);
+                self.emit(Instr::Jump(loop_start), &span.start);
+                let end = self.here();
+                self.patch_jump(exit_jump, end);
+                self.patch_all(loop_frame.break_jumps, end);
+            },
+            ASTNode::FunctionDef { .. } => {
+                // Hoisted and compiled by `Compiler::hoist_functions` up
+                // front, so the definition site itself is a no-op.
+            },
+            ASTNode::ReturnStatement { value, span } => {
+                match value {
+                    Some(expr) => self.compile_expr(expr),
+                    None => {
+                        let none = self.compiler.push_const(Value::None);
+                        self.emit(Instr::PushConst(none), &span.start);
+                    },
+                }
+                self.emit(Instr::Return, &span.start);
+            },
+            ASTNode::BreakStatement { span } => {
+                self.unwind_try_regions_for_loop_exit(&span.start);
+                let idx = self.emit(Instr::Jump(0), &span.start);
+                if let Some(loop_frame) = self.loop_stack.last_mut() {
+                    loop_frame.break_jumps.push(idx);
+                }
+            },
+            ASTNode::ContinueStatement { span } => {
+                self.unwind_try_regions_for_loop_exit(&span.start);
+                let idx = self.emit(Instr::Jump(0), &span.start);
+                if let Some(loop_frame) = self.loop_stack.last_mut() {
+                    loop_frame.continue_jumps.push(idx);
+                }
+            },
+            ASTNode::TryExcept { try_block, handlers, span } => {
+                let try_start = self.emit(Instr::TryStart(Vec::new()), &span.start);
+                self.try_depth += 1;
+                self.compile_block(try_block);
+                self.try_depth -= 1;
+                self.emit(Instr::TryEnd, &span.start);
+                let skip_handlers = self.emit(Instr::Jump(0), &span.start);
+
+                let mut handler_specs = Vec::with_capacity(handlers.len());
+                let mut end_jumps = Vec::with_capacity(handlers.len());
+                for handler in handlers {
+                    let pc = self.here();
+                    match handler.binding {
+                        Some(name) => {
+                            let slot = self.slots.get_or_create(name);
+                            self.emit(Instr::StoreVar(slot), &span.start);
+                        },
+                        None => {
+                            self.emit(Instr::Pop, &span.start);
+                        },
+                    }
+                    self.compile_block(&handler.body);
+                    end_jumps.push(self.emit(Instr::Jump(0), &span.start));
+                    handler_specs.push(HandlerSpec { code_filter: handler.code_filter.clone(), pc });
+                }
+                self.code[try_start] = Instr::TryStart(handler_specs);
+
+                let end = self.here();
+                self.patch_jump(skip_handlers, end);
+                self.patch_all(end_jumps, end);
+            },
+            ASTNode::IndexAssignment { object, index, value, span } => {
+                self.compile_expr(value);
+                self.compile_expr(object);
+                self.compile_expr(index);
+                self.emit(Instr::StoreIndex, &span.start);
+                self.compile_store_into(object, &span.start);
+            },
+            _ => {
+                // Any other node used as a bare statement (shouldn't occur
+                // given the grammar) is treated as an expression whose
+                // value is discarded.
+                self.compile_expr(node);
+                self.emit(Instr::Pop, node.position());
+            },
+        }
+    }
+
+    /// Writes the value on top of the stack into `target`, which is either
+    /// a plain variable or an index chain (`a[i]`, `a[i][j]`, ...). Values
+    /// here have no reference semantics, so a nested target is resolved by
+    /// recursively mutating a clone of each container and writing it back
+    /// one level up — `a[i][j] = x` loads `a[i]`, mutates its clone at
+    /// `j`, then recurses to write that clone back into `a` at `i`.
+    fn compile_store_into(&mut self, target: &ASTNode, span_start: &Position) {
+        match target {
+            ASTNode::Identifier { name, .. } => {
+                let slot = self.slots.get_or_create(*name);
+                self.emit(Instr::StoreVar(slot), span_start);
+            },
+            ASTNode::IndexAccess { object, index, .. } => {
+                self.compile_expr(object);
+                self.compile_expr(index);
+                self.emit(Instr::StoreIndex, span_start);
+                self.compile_store_into(object, span_start);
+            },
+            _ => unreachable!("index assignment target must be a variable or index chain"),
+        }
+    }
+
+    fn compile_expr(&mut self, node: &ASTNode) {
+        match node {
+            ASTNode::Literal { value, span } => {
+                let constant = match value {
+                    LiteralValue::String(s) => Value::String(self.compiler.interner.resolve(*s).to_string()),
+                    LiteralValue::Number(n) => Value::Number(*n),
+                    LiteralValue::Integer(i) => Value::Integer(*i),
+                    LiteralValue::Char(c) => Value::String(c.to_string()),
+                    LiteralValue::Boolean(b) => Value::Boolean(*b),
+                    LiteralValue::None => Value::None,
+                };
+                let idx = self.compiler.push_const(constant);
+                self.emit(Instr::PushConst(idx), &span.start);
+            },
+            ASTNode::Identifier { name, span } => {
+                let slot = self.slots.get_or_create(*name);
+                self.emit(Instr::LoadVar(slot), &span.start);
+            },
+            ASTNode::BinaryOp { left, operator, right, span } if matches!(operator.as_str(), "|>" | "|:" | "|?") => {
+                self.compile_expr(left);
+                let func_name = match right.as_ref() {
+                    ASTNode::Identifier { name, .. } => *name,
+                    _ => unreachable!("the parser only accepts a bare function name here"),
+                };
+                let instr = match operator.as_str() {
+                    "|>" => Instr::PipeApply(func_name),
+                    "|:" => Instr::PipeMap(func_name),
+                    "|?" => Instr::PipeFilter(func_name),
+                    _ => unreachable!(),
+                };
+                self.emit(instr, &span.start);
+            },
+            ASTNode::BinaryOp { left, operator, right, span } if operator == "|&" => {
+                self.compile_expr(left);
+                self.compile_expr(right);
+                self.emit(Instr::Concat, &span.start);
+            },
+            ASTNode::BinaryOp { left, operator, right, span } => {
+                self.compile_expr(left);
+                self.compile_expr(right);
+                let instr = match operator.as_str() {
+                    "+" => Instr::Add,
+                    "-" => Instr::Sub,
+                    "*" => Instr::Mul,
+                    "/" => Instr::Div,
+                    "//" => Instr::Div,
+                    "%" => Instr::Mod,
+                    "**" => Instr::Pow,
+                    "=" => Instr::Cmp(CmpOp::Eq),
+                    "!=" => Instr::Cmp(CmpOp::NotEq),
+                    "<" => Instr::Cmp(CmpOp::Lt),
+                    ">" => Instr::Cmp(CmpOp::Gt),
+                    "<=" => Instr::Cmp(CmpOp::LessEq),
+                    ">=" => Instr::Cmp(CmpOp::GreaterEq),
+                    "et" => Instr::And,
+                    "ou" => Instr::Or,
+                    _ => unreachable!("parser never produces an unknown binary operator"),
+                };
+                self.emit(instr, &span.start);
+            },
+            ASTNode::UnaryOp { operator, operand, span } => {
+                self.compile_expr(operand);
+                let instr = match operator.as_str() {
+                    "-" => Instr::Neg,
+                    "non" => Instr::Not,
+                    _ => unreachable!("parser never produces an unknown unary operator"),
+                };
+                self.emit(instr, &span.start);
+            },
+            ASTNode::FunctionCall { name, arguments, span, .. } => {
+                let plier = self.compiler.interner.intern("plier");
+                if *name == plier && arguments.len() == 3 {
+                    if let ASTNode::Identifier { name: func_name, .. } = &arguments[2] {
+                        self.compile_expr(&arguments[0]);
+                        self.compile_expr(&arguments[1]);
+                        self.emit(Instr::Fold(*func_name), &span.start);
+                        return;
+                    }
+                }
+
+                let lancer = self.compiler.interner.intern("lancer");
+                if *name == lancer && !arguments.is_empty() {
+                    if let ASTNode::Identifier { name: func_name, .. } = &arguments[0] {
+                        for arg in &arguments[1..] {
+                            self.compile_expr(arg);
+                        }
+                        self.emit(Instr::Spawn(*func_name, arguments.len() - 1), &span.start);
+                        return;
+                    }
+                }
+
+                for arg in arguments {
+                    self.compile_expr(arg);
+                }
+                self.emit(Instr::Call(*name, arguments.len()), &span.start);
+            },
+            ASTNode::ListNode { elements, span, .. } => {
+                for elem in elements {
+                    self.compile_expr(elem);
+                }
+                self.emit(Instr::MakeList(elements.len()), &span.start);
+            },
+            ASTNode::DictNode { pairs, span } => {
+                for (key, value) in pairs {
+                    self.compile_expr(key);
+                    self.compile_expr(value);
+                }
+                self.emit(Instr::MakeDict(pairs.len()), &span.start);
+            },
+            ASTNode::IndexAccess { object, index, span } => {
+                self.compile_expr(object);
+                self.compile_expr(index);
+                self.emit(Instr::Index, &span.start);
+            },
+            ASTNode::Interpolation { parts, span } => {
+                for part in parts {
+                    match part {
+                        InterpolationPart::Literal(s) => {
+                            let constant = Value::String(self.compiler.interner.resolve(*s).to_string());
+                            let idx = self.compiler.push_const(constant);
+                            self.emit(Instr::PushConst(idx), &span.start);
+                        },
+                        InterpolationPart::Expr(expr) => self.compile_expr(expr),
+                    }
+                }
+                self.emit(Instr::Interpolate(parts.len()), &span.start);
+            },
+            ASTNode::Assignment { .. }
+            | ASTNode::IfStatement { .. }
+            | ASTNode::WhileLoop { .. }
+            | ASTNode::RepeatLoop { .. }
+            | ASTNode::ForEachLoop { .. }
+            | ASTNode::IndexAssignment { .. }
+            | ASTNode::ExpressionStatement { .. } => {
+                // These only ever appear as statements, never nested
+                // inside an expression; the grammar doesn't produce them
+                // here.
+                unreachable!("statement node compiled as an expression")
+            },
+            _ => unreachable!("the parser never produces this node"),
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use colored::*;
 use std::fmt;
+use std::str::FromStr;
+use crate::ast::Span;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ErrorSeverity {
@@ -26,6 +28,21 @@ impl ErrorSeverity {
     }
 }
 
+impl FromStr for ErrorSeverity {
+    type Err = ();
+
+    /// Parses the uppercase labels produced by `label()`, so tooling that
+    /// round-tripped a severity through JSON can read it back.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "FAIBLE" => Ok(ErrorSeverity::Faible),
+            "MOYENNE" => Ok(ErrorSeverity::Moyenne),
+            "FORTE" => Ok(ErrorSeverity::Forte),
+            _ => Err(()),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ErrorDefinition {
     pub code: &'static str,
@@ -38,15 +55,22 @@ pub struct ErrorDefinition {
     pub example: &'static str,
 }
 
-#[derive(Debug)]
+/// `Clone` so a task result computed on a worker thread (see
+/// `vm::TaskState`) can be handed back to `tache_prete`/`attendre_tache`
+/// more than once without consuming the cached value.
+#[derive(Debug, Clone)]
 pub struct MeowLangError {
     pub error_def: ErrorDefinition,
     pub file: String,
     pub line: usize,
     pub column: usize,
     pub instruction: String,
-    pub context_lines: Vec<String>,
+    pub context_lines: Vec<(usize, String)>,
     pub extra_info: Vec<(String, String)>,
+    /// Spans to underline once the context is printed: the span itself,
+    /// a short message to print next to the carets, and whether it's the
+    /// primary span (`^^^`) or a secondary one (`~~~`).
+    pub labels: Vec<(Span, String, bool)>,
 }
 
 impl MeowLangError {
@@ -59,24 +83,36 @@ impl MeowLangError {
             instruction: String::new(),
             context_lines: Vec::new(),
             extra_info: Vec::new(),
+            labels: Vec::new(),
         }
     }
-    
+
     pub fn with_instruction(mut self, instruction: String) -> Self {
         self.instruction = instruction;
         self
     }
-    
+
     pub fn with_context(mut self, source_lines: &[String]) -> Self {
         self.context_lines = extract_context(source_lines, self.line);
         self
     }
-    
+
     pub fn with_extra(mut self, key: String, value: String) -> Self {
         self.extra_info.push((key, value));
         self
     }
-    
+
+    pub fn with_label(mut self, span: Span, message: String, primary: bool) -> Self {
+        self.labels.push((span, message, primary));
+        self
+    }
+
+    /// The fully-substituted technical message, e.g. for binding a caught
+    /// error into a MeowLang value (`attrape`) or other non-rendering uses.
+    pub fn message(&self) -> String {
+        self.format_message(self.error_def.message_tech)
+    }
+
     fn format_message(&self, template: &str) -> String {
         let mut message = template.to_string();
         for (key, value) in &self.extra_info {
@@ -84,6 +120,110 @@ impl MeowLangError {
         }
         message
     }
+
+    /// Renders the caret/tilde underline(s) for `line_no`, one per label
+    /// whose span touches that line. Multi-line spans are truncated at the
+    /// line boundary so each underline only ever covers one physical line.
+    fn render_labels_for_line(&self, line_no: usize, line_text: &str) -> Vec<String> {
+        const GUTTER: &str = "          "; // matches "{}  {:3} | " (2+2+3+3)
+        let line_len = line_text.chars().count();
+
+        self.labels.iter().filter_map(|(span, message, primary)| {
+            if line_no < span.start.line || line_no > span.end.line {
+                return None;
+            }
+
+            let start_col = if line_no == span.start.line { span.start.column } else { 1 };
+            let end_col = if line_no == span.end.line {
+                span.end.column.max(start_col + 1)
+            } else {
+                line_len + 1
+            };
+
+            let pad = " ".repeat(start_col.saturating_sub(1));
+            let marker_char = if *primary { '^' } else { '~' };
+            let markers: String = std::iter::repeat(marker_char).take(end_col - start_col).collect();
+            let markers = if *primary { markers.red().bold().to_string() } else { markers.yellow().to_string() };
+
+            Some(if message.is_empty() {
+                format!("{}{}{}", GUTTER, pad, markers)
+            } else {
+                format!("{}{}{} {}", GUTTER, pad, markers, message)
+            })
+        }).collect()
+    }
+
+    /// `extra_info` entries that never got substituted into any of this
+    /// error's message templates — e.g. a value a `with_extra` caller
+    /// attached purely for the renderer's sake rather than for `{key}`
+    /// interpolation. Rendered as a standalone "Note :" section instead of
+    /// being silently dropped. Returns `None` rather than an empty `Vec` so
+    /// callers can `if let Some(...)` without an extra emptiness check.
+    fn notes(&self) -> Option<Vec<(&str, &str)>> {
+        let templates = [
+            self.error_def.message_tech,
+            self.error_def.message_meow,
+            self.error_def.mood,
+            self.error_def.suggestion,
+            self.error_def.example,
+        ];
+        let notes: Vec<(&str, &str)> = self.extra_info.iter()
+            .filter(|(key, _)| !templates.iter().any(|t| t.contains(&format!("{{{}}}", key))))
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        if notes.is_empty() { None } else { Some(notes) }
+    }
+
+    /// Serializes this diagnostic to a single-line JSON object, stable
+    /// enough for editors/LSP tooling to parse without scraping the
+    /// emoji-decorated `Display` output. Severities and codes round-trip
+    /// through `ErrorSeverity::from_str` and `ErrorCatalog::get`.
+    pub fn to_json(&self) -> String {
+        let labels: Vec<String> = self.labels.iter().map(|(span, message, primary)| {
+            format!(
+                "{{\"start\":{{\"line\":{},\"column\":{}}},\"end\":{{\"line\":{},\"column\":{}}},\"message\":{},\"primary\":{}}}",
+                span.start.line, span.start.column,
+                span.end.line, span.end.column,
+                json_string(message),
+                primary,
+            )
+        }).collect();
+
+        format!(
+            "{{\"code\":{},\"name\":{},\"severity\":{},\"file\":{},\"line\":{},\"column\":{},\"span\":{{\"start\":{{\"line\":{},\"column\":{}}},\"end\":{{\"line\":{},\"column\":{}}}}},\"message_tech\":{},\"suggestion\":{},\"labels\":[{}]}}",
+            json_string(self.error_def.code),
+            json_string(self.error_def.name),
+            json_string(self.error_def.severity.label()),
+            json_string(&self.file),
+            self.line,
+            self.column,
+            self.line, self.column, self.line, self.column,
+            json_string(&self.format_message(self.error_def.message_tech)),
+            json_string(&self.format_message(self.error_def.suggestion)),
+            labels.join(","),
+        )
+    }
+}
+
+/// Escapes `text` as a JSON string literal, quotes included. Shared with
+/// `ast_json`, which needs the same escaping for identifier/string-literal
+/// text pulled out of the `Interner`.
+pub(crate) fn json_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
 }
 
 impl fmt::Display for MeowLangError {
@@ -114,8 +254,21 @@ impl fmt::Display for MeowLangError {
         if !self.context_lines.is_empty() {
             writeln!(f)?;
             writeln!(f, "Contexte :")?;
-            for line_text in &self.context_lines {
-                writeln!(f, "{}", line_text)?;
+            let width = terminal_width().saturating_sub(10);
+            for (line_no, line_text) in &self.context_lines {
+                let prefix = if *line_no == self.line { "> " } else { "  " };
+                writeln!(f, "{}  {:3} | {}", prefix, line_no, clamp_line(line_text, width))?;
+                for underline in self.render_labels_for_line(*line_no, line_text) {
+                    writeln!(f, "{}", underline)?;
+                }
+            }
+        }
+
+        if let Some(notes) = self.notes() {
+            writeln!(f)?;
+            writeln!(f, "Note :")?;
+            for (key, value) in notes {
+                writeln!(f, "  {} = {}", key, value)?;
             }
         }
         
@@ -146,17 +299,79 @@ impl fmt::Display for MeowLangError {
 
 impl std::error::Error for MeowLangError {}
 
-fn extract_context(source_lines: &[String], error_line: usize) -> Vec<String> {
+/// Collects diagnostics instead of aborting on the first one, so a single
+/// pass over a file can report every mistake instead of just the first.
+/// Once `max` is reached, a final `E999` ("too many errors") is appended
+/// and the sink stops accepting new diagnostics.
+pub struct DiagnosticSink {
+    pub errors: Vec<MeowLangError>,
+    pub max: usize,
+}
+
+impl DiagnosticSink {
+    pub fn new(max: usize) -> Self {
+        DiagnosticSink { errors: Vec::new(), max }
+    }
+
+    /// Records a diagnostic. Returns `true` once the sink is full and the
+    /// caller should stop parsing/lexing altogether.
+    pub fn push(&mut self, error: MeowLangError) -> bool {
+        if self.errors.len() >= self.max {
+            return true;
+        }
+
+        let (file, line, column) = (error.file.clone(), error.line, error.column);
+        self.errors.push(error);
+
+        if self.errors.len() >= self.max {
+            self.errors.push(MeowLangError::new(ErrorCatalog::get("E999"), file, line, column));
+            return true;
+        }
+
+        false
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.errors.len() >= self.max
+    }
+}
+
+impl Default for DiagnosticSink {
+    fn default() -> Self {
+        DiagnosticSink::new(20)
+    }
+}
+
+/// The column budget for a rendered source line, read from `COLUMNS` (set
+/// by most shells) with a sane fallback for the common case of output
+/// being piped somewhere `COLUMNS` isn't inherited from.
+fn terminal_width() -> usize {
+    std::env::var("COLUMNS").ok().and_then(|v| v.parse().ok()).unwrap_or(120)
+}
+
+/// Truncates `line` (by character, not byte, so multibyte source text
+/// doesn't get cut mid-character) to `width` columns, marking the cut with
+/// a trailing `…`. Caret/tilde underlines are left unclamped — they're
+/// positioned against the untruncated line, so a label past the cutoff
+/// simply points past the visible `…`.
+fn clamp_line(line: &str, width: usize) -> String {
+    if width == 0 || line.chars().count() <= width {
+        return line.to_string();
+    }
+    let mut clamped: String = line.chars().take(width.saturating_sub(1)).collect();
+    clamped.push('…');
+    clamped
+}
+
+fn extract_context(source_lines: &[String], error_line: usize) -> Vec<(usize, String)> {
     let context_size = 2;
     let start = error_line.saturating_sub(context_size).max(1);
     let end = (error_line + context_size).min(source_lines.len());
-    
+
     let mut context = Vec::new();
     for line_no in start..=end {
         if line_no > 0 && line_no <= source_lines.len() {
-            let prefix = if line_no == error_line { "> " } else { "  " };
-            let line_text = &source_lines[line_no - 1];
-            context.push(format!("{}  {:3} | {}", prefix, line_no, line_text));
+            context.push((line_no, source_lines[line_no - 1].clone()));
         }
     }
     context
@@ -284,6 +499,74 @@ impl ErrorCatalog {
                 "🧐 Attend quelque chose.",
                 "✔ Vérifie la syntaxe complète de l'instruction"
             ),
+            "E105" => error_def!(
+                "E105", "ListeArgumentsNonFermee",
+                "Liste d'arguments non fermée : il manque une ')'.",
+                "🐾 Le chat a ouvert une parenthèse et ne l'a jamais refermée.",
+                ErrorSeverity::Moyenne,
+                "🐾 Coincé, patte tendue vers la sortie.",
+                "✔ Ajoute une ')' pour fermer la liste d'arguments"
+            ),
+            "E106" => error_def!(
+                "E106", "NomFonctionManquant",
+                "Nom de fonction attendu après 'fonction'.",
+                "🐱 Ce chat n'a pas de nom de fonction.",
+                ErrorSeverity::Moyenne,
+                "🐱 Perdu, cherche son étiquette.",
+                "✔ Donne un nom à la fonction : fonction nom(...):"
+            ),
+            "E107" => error_def!(
+                "E107", "ParametresFonctionManquants",
+                "Liste de paramètres attendue après le nom de la fonction : il manque un '('.",
+                "🐾 Il manque la panière des paramètres (un '(').",
+                ErrorSeverity::Moyenne,
+                "🐾 Cherche sa panière du regard.",
+                "✔ Ajoute une liste de paramètres : fonction nom(parametre1, parametre2):"
+            ),
+            "E108" => error_def!(
+                "E108", "VariableAttendue",
+                "Nom de variable attendu.",
+                "🐾 Il manque le nom du chat.",
+                ErrorSeverity::Moyenne,
+                "🐾 Cherche une étiquette introuvable.",
+                "✔ Utilise un identifiant valide comme nom de variable"
+            ),
+            "E109" => error_def!(
+                "E109", "AccoladeInterpolationNonFermee",
+                "Accolade '{' non fermée dans une chaîne interpolée.",
+                "🧶 La pelote garde une patte coincée dans l'accolade '{' ouverte.",
+                ErrorSeverity::Moyenne,
+                "🧶 Patte coincée, regarde la pelote d'un air gêné.",
+                "✔ Ajoute une accolade '}' fermante après l'expression\n✔ Pour une accolade littérale, double-la : '{{' ou '}}'",
+                "  ecrire \"Bonjour {nom} !\""
+            ),
+            "E110" => error_def!(
+                "E110", "ExpressionInterpolationInvalide",
+                "L'expression entre accolades d'une chaîne interpolée n'est pas valide.",
+                "🧶 Le fil entre les accolades est emmêlé : ce n'est pas une expression valide.",
+                ErrorSeverity::Moyenne,
+                "🧶 Emmêlé, tire sur le mauvais fil.",
+                "✔ Ne place qu'une expression entre { et } : une variable, un calcul...",
+                "  ecrire \"Le chat a {age} ans\""
+            ),
+            "E111" => error_def!(
+                "E111", "NombreMalFome",
+                "Littéral numérique mal formé.",
+                "🐱 Le chat a trébuché sur ce nombre, il ne sait plus le lire.",
+                ErrorSeverity::Moyenne,
+                "🐱 Trébuche, perd l'équilibre.",
+                "✔ Vérifie les chiffres après 0x/0b/0o\n✔ Place les '_' uniquement entre deux chiffres",
+                "  compte = 1_000_000\n  masque = 0xFF\n  poids = 2.5e3"
+            ),
+            "E112" => error_def!(
+                "E112", "CaractereMalForme",
+                "Littéral de caractère mal formé : il doit contenir exactement un caractère entre guillemets simples.",
+                "🐱 Le chat n'attrape qu'une seule souris à la fois entre ces guillemets.",
+                ErrorSeverity::Moyenne,
+                "🐱 Les deux pattes pleines, il ne peut rien attraper de plus.",
+                "✔ Mets exactement un caractère entre les guillemets simples\n✔ Pour plusieurs caractères, utilise des guillemets doubles",
+                "  initiale = 'C'\n  retour_ligne = '\\n'"
+            ),
             "E200" => error_def!(
                 "E200", "VariableInexistante",
                 "Variable '{var_name}' non définie.",
@@ -293,6 +576,14 @@ impl ErrorCatalog {
                 "✔ Vérifie l'orthographe de la variable\n✔ Définis la variable avant de l'utiliser",
                 "  {var_name} = 42\n  ecrire {var_name}"
             ),
+            "E201" => error_def!(
+                "E201", "CompteurHorsRepeter",
+                "'compteur' n'existe qu'à l'intérieur d'un bloc 'repeter ... fois'.",
+                "🐾 '{var_name}' compte les tours d'un manège qui n'est pas là.",
+                ErrorSeverity::Moyenne,
+                "🐾 Tourne en rond, cherche un manège introuvable.",
+                "✔ N'utilise 'compteur' que dans le corps d'une boucle 'repeter N fois'"
+            ),
             "E202" => error_def!(
                 "E202", "TypeIncompatible",
                 "Opération impossible entre types incompatibles : {type1} et {type2}.",
@@ -301,6 +592,22 @@ impl ErrorCatalog {
                 "😿 Dégoûté par la gamelle.",
                 "✔ Vérifie les types de tes variables\n✔ Convertis si nécessaire"
             ),
+            "E203" => error_def!(
+                "E203", "VariableInutilisee",
+                "Variable '{var_name}' assignée mais jamais utilisée.",
+                "🐱 Ce chat '{var_name}' dort dans un coin, personne ne vient le voir.",
+                ErrorSeverity::Faible,
+                "😺 Fait une sieste, ignoré de tous.",
+                "✔ Supprime la variable si elle est inutile\n✔ Utilise-la si l'oubli est une erreur"
+            ),
+            "E204" => error_def!(
+                "E204", "VariableUtiliseeAvantAffectation",
+                "Variable '{var_name}' utilisée avant d'être assignée.",
+                "🐾 Ce chat '{var_name}' est appelé avant même d'avoir reçu son nom.",
+                ErrorSeverity::Moyenne,
+                "🐾 Répond à un nom qu'on ne lui a pas encore donné.",
+                "✔ Assigne '{var_name}' avant de l'utiliser"
+            ),
             "E300" => error_def!(
                 "E300", "ConditionInvalide",
                 "La condition n'est pas valide ou est mal formée.",
@@ -342,6 +649,22 @@ impl ErrorCatalog {
                 "🐾 Insatisfait du nombre de caresses.",
                 "✔ Vérifie le nombre d'arguments passés à la fonction"
             ),
+            "E602" => error_def!(
+                "E602", "ProfondeurDepassee",
+                "Profondeur d'appel maximale dépassée ({depth} appels imbriqués).",
+                "🐈‍⬛ Le chat s'est empilé {depth} fois et n'ose plus redescendre.",
+                ErrorSeverity::Forte,
+                "🐈‍⬛ Étourdi, tout en haut de la pile.",
+                "✔ Vérifie que ta fonction récursive a bien un cas d'arrêt\n✔ Réduis la profondeur de récursion"
+            ),
+            "E603" => error_def!(
+                "E603", "TacheInconnue",
+                "La tâche #{handle} n'existe pas.",
+                "🐱 Le chat cherche la tâche #{handle}, qui n'a jamais couru.",
+                ErrorSeverity::Moyenne,
+                "🐱 Perplexe, flaire une piste froide.",
+                "✔ Vérifie que le handle vient bien d'un appel à `lancer`"
+            ),
             "E700" => error_def!(
                 "E700", "IndexHorsLimite",
                 "Index {index} hors limites pour liste de taille {size}.",
@@ -351,6 +674,24 @@ impl ErrorCatalog {
                 "✔ Vérifie que l'index est entre 0 et {size_minus_one}",
                 "  # Pour une liste de taille {size}, utilise index 0 à {size_minus_one}"
             ),
+            "E701" => error_def!(
+                "E701", "ClefAbsente",
+                "Clé '{key}' absente du dictionnaire.",
+                "🐾 Le chat ne trouve pas la clé '{key}' dans son panier.",
+                ErrorSeverity::Moyenne,
+                "🐾 Fouille le panier, ne trouve rien.",
+                "✔ Vérifie l'orthographe de la clé\n✔ Ajoute la clé avant de la lire",
+                "  dico = dictionnaire(\"nom\": \"Minou\")\n  ecrire dico[\"nom\"]"
+            ),
+            "E702" => error_def!(
+                "E702", "IndexCaractereHorsLimite",
+                "Index {index} hors limites pour une chaîne de {size} caractère(s).",
+                "🐈 Tu cherches une lettre qui n'existe pas dans ce miaou ({index}).",
+                ErrorSeverity::Moyenne,
+                "🐈 Cherche dans le vide.",
+                "✔ Vérifie que l'index est entre 0 et {size_minus_one}",
+                "  # Pour une chaîne de {size} caractères, utilise index 0 à {size_minus_one}"
+            ),
             "E800" => error_def!(
                 "E800", "TempsNegatif",
                 "La durée d'attente ne peut pas être négative : {duration}.",
@@ -359,6 +700,14 @@ impl ErrorCatalog {
                 "🕰️ Confus par le temps.",
                 "✔ Utilise une durée positive pour 'attendre'"
             ),
+            "E801" => error_def!(
+                "E801", "ParametreInvalide",
+                "Paramètre invalide pour la distribution aléatoire : {parametre}.",
+                "🎲 Le chat refuse de lancer ses dés avec un paramètre pareil ({parametre}).",
+                ErrorSeverity::Moyenne,
+                "🎲 Méfiant, garde les dés dans sa patte.",
+                "✔ `aleatoire_exponentiel` attend un lambda strictement positif"
+            ),
             "E900" => error_def!(
                 "E900", "FichierIntrouvable",
                 "Le fichier '{filename}' est introuvable.",
@@ -367,6 +716,14 @@ impl ErrorCatalog {
                 "😾 Énervé, cherche partout.",
                 "✔ Vérifie le chemin du fichier\n✔ Vérifie que le fichier existe"
             ),
+            "E901" => error_def!(
+                "E901", "EcritureImpossible",
+                "Impossible d'écrire le fichier '{filename}' : {reason}.",
+                "😾 Le chat griffe le papier mais rien ne s'y imprime ('{filename}').",
+                ErrorSeverity::Forte,
+                "😾 Frustré, les pattes pleines d'encre.",
+                "✔ Vérifie que le dossier de destination existe\n✔ Vérifie les droits d'écriture"
+            ),
             "E902" => error_def!(
                 "E902", "CrashInterpreteur",
                 "Erreur interne de l'interpréteur : {reason}.",
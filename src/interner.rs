@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+/// A cheap handle to an interned string, good for O(1) equality and
+/// hashing instead of repeated byte-by-byte string comparisons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(u32);
+
+/// Owns the backing storage for every identifier and string literal seen
+/// during lexing/parsing. The lexer interns as it reads tokens, the parser
+/// interns the handful of names it synthesizes itself (builtin call names,
+/// `compteur`, ...), and later stages resolve a `Symbol` back to text only
+/// when they actually need to display it (error messages, `ecrire`, ...).
+/// `Clone` so a `lancer`'d task can run on its own worker thread against a
+/// snapshot of the interner (see `vm::WorkerPool`) instead of sharing one
+/// across threads — every `Symbol` a task's function body can reference
+/// was already interned by compile time, so the snapshot is complete.
+#[derive(Debug, Default, Clone)]
+pub struct Interner {
+    map: HashMap<String, Symbol>,
+    strings: Vec<String>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Interner {
+            map: HashMap::new(),
+            strings: Vec::new(),
+        }
+    }
+
+    pub fn intern(&mut self, text: &str) -> Symbol {
+        if let Some(&symbol) = self.map.get(text) {
+            return symbol;
+        }
+
+        let symbol = Symbol(self.strings.len() as u32);
+        self.strings.push(text.to_string());
+        self.map.insert(text.to_string(), symbol);
+        symbol
+    }
+
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        &self.strings[symbol.0 as usize]
+    }
+}
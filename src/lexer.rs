@@ -1,36 +1,92 @@
-use crate::token::{Token, TokenType, TokenValue};
+use std::collections::VecDeque;
+
+use crate::ast::{Position, Span};
+use crate::token::{InterpolationSegment, Token, TokenType, TokenValue};
 use crate::error::{ErrorCatalog, MeowLangError};
+use crate::interner::Interner;
 
-pub struct Lexer {
+pub struct Lexer<'a> {
     chars: Vec<char>,
+    /// The original source text, kept verbatim (not just as `chars`/
+    /// `lines`) so `slice` can hand back the exact bytes a token came
+    /// from.
+    source: String,
     filename: String,
     lines: Vec<String>,
     pos: usize,
     line: usize,
     column: usize,
-    tokens: Vec<Token>,
+    /// Byte offset into `source` of the lexer's current position. Tracked
+    /// alongside the char-index `pos` because `chars` is a `Vec<char>`:
+    /// the two diverge as soon as a multibyte character (e.g. an accented
+    /// French identifier) has been consumed.
+    byte_pos: usize,
+    /// The byte offset `start_token` last recorded — read back by
+    /// `push_token` to fill in the token's `byte_range`.
+    token_byte_start: usize,
+    /// Tokens `scan_one` has produced but `next_token` hasn't handed out
+    /// yet — usually at most one, but indentation changes can queue
+    /// several `Dedent`s (or the trailing dedent-flush-plus-`Eof`) from a
+    /// single `scan_one` call.
+    pending: VecDeque<Token>,
     indent_stack: Vec<usize>,
     at_line_start: bool,
+    interner: &'a mut Interner,
+    /// Set once the trailing dedent-flush and `Eof` have been queued, so
+    /// `next_token` knows to stop calling `scan_one` and return `None`
+    /// instead of scanning past the end of `chars` forever.
+    finished: bool,
+    /// Comment lines seen since the last emitted token, queued up to be
+    /// attached as the next token's `leading_trivia` (see `push_token`).
+    pending_trivia: Vec<String>,
 }
 
-impl Lexer {
-    pub fn new(source: String, filename: String) -> Self {
+impl<'a> Lexer<'a> {
+    pub fn new(source: String, filename: String, interner: &'a mut Interner) -> Self {
         let lines: Vec<String> = source.lines().map(|s| s.to_string()).collect();
         let chars: Vec<char> = source.chars().collect();
-        
+
         Lexer {
             chars,
+            source,
             filename,
             lines,
             pos: 0,
             line: 1,
             column: 1,
-            tokens: Vec::new(),
+            byte_pos: 0,
+            token_byte_start: 0,
+            pending: VecDeque::new(),
             indent_stack: vec![0],
             at_line_start: true,
+            interner,
+            finished: false,
+            pending_trivia: Vec::new(),
         }
     }
-    
+
+    /// Returns the exact source text `token` was scanned from, by slicing
+    /// `source` with its byte range — what `meowfmt` or an LSP want
+    /// instead of reconstructing text from a token's type/value.
+    pub fn slice(&self, token: &Token) -> &str {
+        &self.source[token.byte_range.clone()]
+    }
+
+    /// Queues `token` onto `pending`, filling in its `byte_range` from
+    /// `token_byte_start` (see `start_token`) and attaching and clearing
+    /// whatever comment lines `skip_comment` has queued up since the last
+    /// token — the single choke point every `self.push_token(...)` call
+    /// below goes through so callers don't each have to remember to do
+    /// either.
+    fn push_token(&mut self, token: Token) {
+        let mut token = token;
+        token.byte_range = self.token_byte_start..self.byte_pos;
+        if !self.pending_trivia.is_empty() {
+            token.leading_trivia = std::mem::take(&mut self.pending_trivia);
+        }
+        self.pending.push_back(token);
+    }
+
     fn current_char(&self) -> Option<char> {
         if self.pos < self.chars.len() {
             Some(self.chars[self.pos])
@@ -38,7 +94,7 @@ impl Lexer {
             None
         }
     }
-    
+
     fn peek_char(&self, offset: usize) -> Option<char> {
         if self.pos + offset < self.chars.len() {
             Some(self.chars[self.pos + offset])
@@ -46,7 +102,7 @@ impl Lexer {
             None
         }
     }
-    
+
     fn advance(&mut self) {
         if let Some(ch) = self.current_char() {
             if ch == '\n' {
@@ -57,9 +113,30 @@ impl Lexer {
                 self.column += 1;
             }
             self.pos += 1;
+            self.byte_pos += ch.len_utf8();
         }
     }
-    
+
+    /// The current lexer position, used as the start of a not-yet-read token.
+    fn here(&self) -> Position {
+        Position::new(self.line, self.column)
+    }
+
+    /// Like `here`, but also records the current byte offset as the
+    /// token-in-progress's start (see `token_byte_start`) — call this
+    /// instead of `here` at the point each token's scan begins, so
+    /// `push_token` can later compute its `byte_range`.
+    fn start_token(&mut self) -> Position {
+        self.token_byte_start = self.byte_pos;
+        self.here()
+    }
+
+    /// Builds the span from `start` to the lexer's current position, i.e.
+    /// the range just consumed while reading a token.
+    fn span_from(&self, start: Position) -> Span {
+        Span::new(start, self.here())
+    }
+
     fn skip_whitespace(&mut self, skip_newlines: bool) {
         while let Some(ch) = self.current_char() {
             if ch == ' ' || ch == '\t' || ch == '\r' {
@@ -71,45 +148,113 @@ impl Lexer {
             }
         }
     }
-    
+
     fn skip_comment(&mut self) {
         if self.current_char() == Some('#') {
+            self.advance();
+            let mut text = String::new();
             while self.current_char().is_some() && self.current_char() != Some('\n') {
+                text.push(self.current_char().unwrap());
                 self.advance();
             }
+            self.pending_trivia.push(text.trim().to_string());
         }
     }
-    
-    fn read_string(&mut self) -> Result<String, MeowLangError> {
+
+    /// Reads a string literal's body, splitting it into alternating
+    /// literal text and `{expr}` segments. A plain string (no `{`) comes
+    /// back as a single `Literal` segment, same as before interpolation
+    /// existed; the caller collapses that single-segment case straight
+    /// back into a `TokenType::String` token.
+    fn read_string(&mut self) -> Result<Vec<InterpolationSegment>, MeowLangError> {
         let start_line = self.line;
         let start_column = self.column;
         let quote_char = self.current_char().unwrap();
         self.advance();
-        
-        let mut result = String::new();
-        
+
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+
         while self.current_char().is_some() && self.current_char() != Some(quote_char) {
-            if self.current_char() == Some('\\') {
-                self.advance();
-                if let Some(ch) = self.current_char() {
-                    let escaped = match ch {
-                        'n' => '\n',
-                        't' => '\t',
-                        'r' => '\r',
-                        '\\' => '\\',
-                        '"' => '"',
-                        '\'' => '\'',
-                        _ => ch,
-                    };
-                    result.push(escaped);
+            match self.current_char().unwrap() {
+                '\\' => {
                     self.advance();
-                }
-            } else {
-                result.push(self.current_char().unwrap());
-                self.advance();
+                    if let Some(ch) = self.current_char() {
+                        let escaped = match ch {
+                            'n' => '\n',
+                            't' => '\t',
+                            'r' => '\r',
+                            '\\' => '\\',
+                            '"' => '"',
+                            '\'' => '\'',
+                            '{' => '{',
+                            '}' => '}',
+                            _ => ch,
+                        };
+                        literal.push(escaped);
+                        self.advance();
+                    }
+                },
+                '{' if self.peek_char(1) == Some('{') => {
+                    literal.push('{');
+                    self.advance();
+                    self.advance();
+                },
+                '}' if self.peek_char(1) == Some('}') => {
+                    literal.push('}');
+                    self.advance();
+                    self.advance();
+                },
+                '{' => {
+                    segments.push(InterpolationSegment::Literal(self.interner.intern(&literal)));
+                    literal.clear();
+
+                    let brace_line = self.line;
+                    let brace_column = self.column;
+                    self.advance();
+
+                    let mut depth = 1;
+                    let mut expr_source = String::new();
+                    while let Some(ch) = self.current_char() {
+                        match ch {
+                            '{' => {
+                                depth += 1;
+                                expr_source.push(ch);
+                                self.advance();
+                            },
+                            '}' => {
+                                depth -= 1;
+                                self.advance();
+                                if depth == 0 {
+                                    break;
+                                }
+                                expr_source.push(ch);
+                            },
+                            _ => {
+                                expr_source.push(ch);
+                                self.advance();
+                            },
+                        }
+                    }
+
+                    if depth != 0 {
+                        return Err(MeowLangError::new(
+                            ErrorCatalog::get("E109"),
+                            self.filename.clone(),
+                            brace_line,
+                            brace_column,
+                        ).with_context(&self.lines));
+                    }
+
+                    segments.push(InterpolationSegment::Expr(expr_source));
+                },
+                ch => {
+                    literal.push(ch);
+                    self.advance();
+                },
             }
         }
-        
+
         if self.current_char().is_none() {
             return Err(MeowLangError::new(
                 ErrorCatalog::get("E101"),
@@ -118,42 +263,188 @@ impl Lexer {
                 start_column,
             ).with_context(&self.lines));
         }
-        
+
         self.advance();
-        Ok(result)
+        segments.push(InterpolationSegment::Literal(self.interner.intern(&literal)));
+        Ok(segments)
     }
-    
-    fn read_number(&mut self) -> (f64, bool) {
-        let start = self.pos;
-        let mut has_dot = false;
-        
-        while let Some(ch) = self.current_char() {
-            if ch.is_ascii_digit() {
+
+    /// Reads a character literal's body: exactly one logical character
+    /// (after escape processing) between single quotes. `\n`, `\t`, `\r`,
+    /// `\\`, `\'`, `\"` and `\0` mirror the escapes `read_string` accepts;
+    /// `\u{...}` (a hex Unicode scalar value) is new here and not yet
+    /// supported in double-quoted strings. Anything else — zero
+    /// characters (`''`), more than one, an unterminated literal, or a
+    /// malformed escape — is `E112`.
+    fn read_char(&mut self) -> Result<char, MeowLangError> {
+        let start_line = self.line;
+        let start_column = self.column;
+        self.advance();
+
+        let value = match self.current_char() {
+            Some('\\') => {
                 self.advance();
-            } else if ch == '.' && !has_dot {
-                if let Some(next) = self.peek_char(1) {
-                    if next.is_ascii_digit() {
-                        has_dot = true;
+                let escaped = match self.current_char() {
+                    Some('n') => '\n',
+                    Some('t') => '\t',
+                    Some('r') => '\r',
+                    Some('\\') => '\\',
+                    Some('\'') => '\'',
+                    Some('"') => '"',
+                    Some('0') => '\0',
+                    Some('u') => {
                         self.advance();
-                    } else {
-                        break;
-                    }
-                } else {
-                    break;
+                        if self.current_char() != Some('{') {
+                            return Err(self.malformed_char_error(start_line, start_column));
+                        }
+                        self.advance();
+
+                        let mut hex = String::new();
+                        while matches!(self.current_char(), Some(ch) if ch != '}') {
+                            hex.push(self.current_char().unwrap());
+                            self.advance();
+                        }
+                        if self.current_char() != Some('}') || hex.is_empty() {
+                            return Err(self.malformed_char_error(start_line, start_column));
+                        }
+
+                        let code = u32::from_str_radix(&hex, 16).ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| self.malformed_char_error(start_line, start_column))?;
+                        self.advance();
+                        if self.current_char() != Some('\'') {
+                            return Err(self.malformed_char_error(start_line, start_column));
+                        }
+                        self.advance();
+                        return Ok(code);
+                    },
+                    _ => return Err(self.malformed_char_error(start_line, start_column)),
+                };
+                self.advance();
+                escaped
+            },
+            Some('\'') | None => return Err(self.malformed_char_error(start_line, start_column)),
+            Some(ch) => {
+                self.advance();
+                ch
+            },
+        };
+
+        if self.current_char() != Some('\'') {
+            return Err(self.malformed_char_error(start_line, start_column));
+        }
+        self.advance();
+
+        Ok(value)
+    }
+
+    fn malformed_char_error(&self, line: usize, column: usize) -> MeowLangError {
+        MeowLangError::new(ErrorCatalog::get("E112"), self.filename.clone(), line, column)
+            .with_context(&self.lines)
+    }
+
+    /// Reads a run of digits matching `is_digit`, allowing `_` separators
+    /// strictly between two digits — never leading, trailing, doubled, or
+    /// adjacent to a radix prefix or a decimal point — and returns the
+    /// digits with every `_` stripped out. `start` anchors the `E111`
+    /// diagnostic at the literal's first character rather than wherever
+    /// the bad `_` happens to sit.
+    fn read_digit_group(&mut self, is_digit: impl Fn(char) -> bool, start: &Position) -> Result<String, MeowLangError> {
+        let mut digits = String::new();
+        let mut last_was_underscore = false;
+
+        while let Some(ch) = self.current_char() {
+            if is_digit(ch) {
+                digits.push(ch);
+                last_was_underscore = false;
+                self.advance();
+            } else if ch == '_' {
+                let valid = !digits.is_empty() && !last_was_underscore
+                    && matches!(self.peek_char(1), Some(next) if is_digit(next));
+                if !valid {
+                    return Err(self.malformed_number_error(&start));
                 }
+                last_was_underscore = true;
+                self.advance();
             } else {
                 break;
             }
         }
-        
-        let number_str: String = self.chars[start..self.pos].iter().collect();
-        let number = number_str.parse::<f64>().unwrap_or(0.0);
-        (number, has_dot)
+
+        if digits.is_empty() {
+            return Err(self.malformed_number_error(&start));
+        }
+        Ok(digits)
+    }
+
+    fn malformed_number_error(&self, start: &Position) -> MeowLangError {
+        MeowLangError::new(ErrorCatalog::get("E111"), self.filename.clone(), start.line, start.column)
+            .with_context(&self.lines)
     }
-    
+
+    /// Reads a numeric literal: a `0x`/`0b`/`0o`-prefixed integer, or a
+    /// decimal with an optional fractional part and an optional `e`/`E`
+    /// exponent (which, like a fractional part, forces the float branch).
+    /// `_` digit-group separators are accepted throughout (see
+    /// `read_digit_group`). The returned `bool` is `true` when the token
+    /// should become a `Number` (float) rather than an `Integer` — true
+    /// for any literal with a dot or an exponent, false for plain decimal
+    /// and radix-prefixed integers.
+    fn read_number(&mut self) -> Result<(f64, bool), MeowLangError> {
+        let start = self.here();
+
+        if self.current_char() == Some('0') {
+            let radix = match self.peek_char(1) {
+                Some('x') | Some('X') => Some(16u32),
+                Some('b') | Some('B') => Some(2u32),
+                Some('o') | Some('O') => Some(8u32),
+                _ => None,
+            };
+            if let Some(radix) = radix {
+                self.advance();
+                self.advance();
+                let digits = self.read_digit_group(|ch| ch.is_digit(radix), &start)?;
+                let value = i64::from_str_radix(&digits, radix)
+                    .map_err(|_| self.malformed_number_error(&start))?;
+                return Ok((value as f64, false));
+            }
+        }
+
+        let mut text = self.read_digit_group(|ch| ch.is_ascii_digit(), &start)?;
+        let mut is_float = false;
+
+        if self.current_char() == Some('.') {
+            if let Some(next) = self.peek_char(1) {
+                if next.is_ascii_digit() {
+                    is_float = true;
+                    text.push('.');
+                    self.advance();
+                    text.push_str(&self.read_digit_group(|ch| ch.is_ascii_digit(), &start)?);
+                }
+            }
+        }
+
+        if matches!(self.current_char(), Some('e') | Some('E')) {
+            let sign_offset = if matches!(self.peek_char(1), Some('+') | Some('-')) { 2 } else { 1 };
+            if matches!(self.peek_char(sign_offset), Some(ch) if ch.is_ascii_digit()) {
+                is_float = true;
+                text.push(self.current_char().unwrap());
+                self.advance();
+                if sign_offset == 2 {
+                    text.push(self.current_char().unwrap());
+                    self.advance();
+                }
+                text.push_str(&self.read_digit_group(|ch| ch.is_ascii_digit(), &start)?);
+            }
+        }
+
+        let number = text.parse::<f64>().map_err(|_| self.malformed_number_error(&start))?;
+        Ok((number, is_float))
+    }
+
     fn read_identifier(&mut self) -> String {
         let start = self.pos;
-        
+
         while let Some(ch) = self.current_char() {
             if ch.is_alphanumeric() || ch == '_' {
                 self.advance();
@@ -161,20 +452,20 @@ impl Lexer {
                 break;
             }
         }
-        
+
         self.chars[start..self.pos].iter().collect()
     }
-    
-    fn get_keyword_token(&mut self, identifier: &str, line: usize, column: usize) -> Token {
+
+    fn get_keyword_token(&mut self, identifier: &str, start: Position) -> Token {
         let lower = identifier.to_lowercase();
-        
+
         match lower.as_str() {
-            "miaou" => Token::simple(TokenType::Miaou, line, column),
-            "meow" => Token::simple(TokenType::Meow, line, column),
-            "ecrire" => Token::simple(TokenType::Ecrire, line, column),
-            "demander" => Token::simple(TokenType::Demander, line, column),
-            "si" => Token::simple(TokenType::Si, line, column),
-            "alors" => Token::simple(TokenType::Alors, line, column),
+            "miaou" => Token::simple(TokenType::Miaou, self.span_from(start)),
+            "meow" => Token::simple(TokenType::Meow, self.span_from(start)),
+            "ecrire" => Token::simple(TokenType::Ecrire, self.span_from(start)),
+            "demander" => Token::simple(TokenType::Demander, self.span_from(start)),
+            "si" => Token::simple(TokenType::Si, self.span_from(start)),
+            "alors" => Token::simple(TokenType::Alors, self.span_from(start)),
             "sinon" => {
                 self.skip_whitespace(false);
                 if let Some(ch) = self.current_char() {
@@ -182,14 +473,14 @@ impl Lexer {
                         let next_word = self.peek_identifier();
                         if next_word.to_lowercase() == "si" {
                             self.read_identifier();
-                            return Token::simple(TokenType::SinonSi, line, column);
+                            return Token::simple(TokenType::SinonSi, self.span_from(start));
                         }
                     }
                 }
-                Token::simple(TokenType::Sinon, line, column)
+                Token::simple(TokenType::Sinon, self.span_from(start))
             },
-            "repeter" => Token::simple(TokenType::Repeter, line, column),
-            "fois" => Token::simple(TokenType::Fois, line, column),
+            "repeter" => Token::simple(TokenType::Repeter, self.span_from(start)),
+            "fois" => Token::simple(TokenType::Fois, self.span_from(start)),
             "tant" => {
                 self.skip_whitespace(false);
                 if let Some(ch) = self.current_char() {
@@ -197,11 +488,11 @@ impl Lexer {
                         let next_word = self.peek_identifier();
                         if next_word.to_lowercase() == "que" {
                             self.read_identifier();
-                            return Token::simple(TokenType::TantQue, line, column);
+                            return Token::simple(TokenType::TantQue, self.span_from(start));
                         }
                     }
                 }
-                Token::new(TokenType::Identifier, TokenValue::String(identifier.to_string()), line, column)
+                Token::new(TokenType::Identifier, TokenValue::String(self.interner.intern(identifier)), self.span_from(start))
             },
             "pour" => {
                 self.skip_whitespace(false);
@@ -210,51 +501,42 @@ impl Lexer {
                         let next_word = self.peek_identifier();
                         if next_word.to_lowercase() == "chaque" {
                             self.read_identifier();
-                            return Token::simple(TokenType::PourChaque, line, column);
+                            return Token::simple(TokenType::PourChaque, self.span_from(start));
                         }
                     }
                 }
-                Token::new(TokenType::Identifier, TokenValue::String(identifier.to_string()), line, column)
+                Token::new(TokenType::Identifier, TokenValue::String(self.interner.intern(identifier)), self.span_from(start))
             },
-            "dans" => Token::simple(TokenType::Dans, line, column),
-            "compteur" => Token::simple(TokenType::Compteur, line, column),
-            "fonction" => Token::simple(TokenType::Fonction, line, column),
-            "retour" => Token::simple(TokenType::Retour, line, column),
-            "liste" => Token::simple(TokenType::Liste, line, column),
-            "dictionnaire" => Token::simple(TokenType::Dictionnaire, line, column),
-            "essayer" => Token::simple(TokenType::Essayer, line, column),
-            "sauf" => Token::simple(TokenType::Sauf, line, column),
-            "erreur" => Token::simple(TokenType::Erreur, line, column),
-            "importer" => Token::simple(TokenType::Importer, line, column),
-            "minuscule" => Token::simple(TokenType::Minuscule, line, column),
-            "majuscule" => Token::simple(TokenType::Majuscule, line, column),
-            "longueur" => Token::simple(TokenType::Longueur, line, column),
-            "remplacer" => Token::simple(TokenType::Remplacer, line, column),
-            "contient" => Token::simple(TokenType::Contient, line, column),
-            "aleatoire" => Token::simple(TokenType::Aleatoire, line, column),
-            "sqrt" => Token::simple(TokenType::Sqrt, line, column),
-            "abs" => Token::simple(TokenType::Abs, line, column),
-            "round" => Token::simple(TokenType::Round, line, column),
-            "floor" => Token::simple(TokenType::Floor, line, column),
-            "ceil" => Token::simple(TokenType::Ceil, line, column),
-            "ouvrir" => Token::simple(TokenType::Ouvrir, line, column),
-            "lire" => Token::simple(TokenType::Lire, line, column),
-            "fermer" => Token::simple(TokenType::Fermer, line, column),
-            "attendre" => Token::simple(TokenType::Attendre, line, column),
-            "vrai" => Token::new(TokenType::Boolean, TokenValue::Boolean(true), line, column),
-            "faux" => Token::new(TokenType::Boolean, TokenValue::Boolean(false), line, column),
-            "et" => Token::simple(TokenType::Et, line, column),
-            "ou" => Token::simple(TokenType::Ou, line, column),
-            "non" => Token::simple(TokenType::Non, line, column),
-            "a" => Token::simple(TokenType::A, line, column),
-            _ => Token::new(TokenType::Identifier, TokenValue::String(identifier.to_string()), line, column),
+            "dans" => Token::simple(TokenType::Dans, self.span_from(start)),
+            "compteur" => Token::simple(TokenType::Compteur, self.span_from(start)),
+            "fonction" => Token::simple(TokenType::Fonction, self.span_from(start)),
+            "retour" => Token::simple(TokenType::Retour, self.span_from(start)),
+            "casser" => Token::simple(TokenType::Casser, self.span_from(start)),
+            "continuer" => Token::simple(TokenType::Continuer, self.span_from(start)),
+            "liste" => Token::simple(TokenType::Liste, self.span_from(start)),
+            "dictionnaire" => Token::simple(TokenType::Dictionnaire, self.span_from(start)),
+            "essayer" => Token::simple(TokenType::Essayer, self.span_from(start)),
+            "sauf" => Token::simple(TokenType::Sauf, self.span_from(start)),
+            "erreur" => Token::simple(TokenType::Erreur, self.span_from(start)),
+            "comme" => Token::simple(TokenType::Comme, self.span_from(start)),
+            "importer" => Token::simple(TokenType::Importer, self.span_from(start)),
+            "ouvrir" => Token::simple(TokenType::Ouvrir, self.span_from(start)),
+            "lire" => Token::simple(TokenType::Lire, self.span_from(start)),
+            "fermer" => Token::simple(TokenType::Fermer, self.span_from(start)),
+            "vrai" => Token::new(TokenType::Boolean, TokenValue::Boolean(true), self.span_from(start)),
+            "faux" => Token::new(TokenType::Boolean, TokenValue::Boolean(false), self.span_from(start)),
+            "et" => Token::simple(TokenType::Et, self.span_from(start)),
+            "ou" => Token::simple(TokenType::Ou, self.span_from(start)),
+            "non" => Token::simple(TokenType::Non, self.span_from(start)),
+            "a" => Token::simple(TokenType::A, self.span_from(start)),
+            _ => Token::new(TokenType::Identifier, TokenValue::String(self.interner.intern(identifier)), self.span_from(start)),
         }
     }
-    
+
     fn peek_identifier(&self) -> String {
         let mut pos = self.pos;
         let mut result = String::new();
-        
+
         while pos < self.chars.len() {
             let ch = self.chars[pos];
             if ch.is_alphanumeric() || ch == '_' {
@@ -264,240 +546,407 @@ impl Lexer {
                 break;
             }
         }
-        
+
         result
     }
-    
+
     fn handle_indentation(&mut self, indent_level: usize) {
+        // Indent/Dedent markers don't correspond to any source text of
+        // their own (no `advance()` happens between here and their
+        // `push_token` calls below), so they get a zero-width byte range
+        // at the current position.
+        self.token_byte_start = self.byte_pos;
         let current_indent = *self.indent_stack.last().unwrap();
-        
+
         if indent_level > current_indent {
             self.indent_stack.push(indent_level);
-            self.tokens.push(Token::new(
+            self.push_token(Token::new(
                 TokenType::Indent,
                 TokenValue::Indent(indent_level),
-                self.line,
-                1,
+                Span::point(Position::new(self.line, 1)),
             ));
         } else if indent_level < current_indent {
             while !self.indent_stack.is_empty() && *self.indent_stack.last().unwrap() > indent_level {
                 self.indent_stack.pop();
-                self.tokens.push(Token::new(
+                self.push_token(Token::new(
                     TokenType::Dedent,
                     TokenValue::Indent(indent_level),
-                    self.line,
-                    1,
+                    Span::point(Position::new(self.line, 1)),
                 ));
             }
         }
     }
-    
-    pub fn tokenize(&mut self) -> Result<Vec<Token>, MeowLangError> {
-        while self.pos < self.chars.len() {
-            if self.at_line_start {
-                let mut indent_level = 0;
-                
-                while let Some(ch) = self.current_char() {
-                    if ch == ' ' {
-                        indent_level += 1;
-                        self.advance();
-                    } else if ch == '\t' {
-                        indent_level += 4;
-                        self.advance();
-                    } else {
-                        break;
-                    }
-                }
-                
-                if self.current_char() == Some('#') {
-                    self.skip_comment();
-                    continue;
-                }
-                
-                if self.current_char() == Some('\n') {
+
+    /// Performs one step of the lexer's scan — at most the work of a
+    /// single iteration of the old eager `tokenize`'s `while` loop — and
+    /// queues whatever token(s) that produced onto `pending` rather than
+    /// returning them directly. A step very often queues nothing at all
+    /// (skipping whitespace or a comment just advances `pos`) or exactly
+    /// one token, but indentation changes can queue several `Dedent`s —
+    /// or, once the source is exhausted, the trailing dedent-flush plus
+    /// `Eof` — from a single call. `next_token` is what turns that into a
+    /// proper one-token-at-a-time stream: it drains `pending` first and
+    /// only calls this when it runs dry.
+    fn scan_one(&mut self) -> Result<(), MeowLangError> {
+        if self.pos >= self.chars.len() {
+            self.finish();
+            return Ok(());
+        }
+
+        if self.at_line_start {
+            let mut indent_level = 0;
+
+            while let Some(ch) = self.current_char() {
+                if ch == ' ' {
+                    indent_level += 1;
                     self.advance();
-                    continue;
-                }
-                
-                if self.current_char().is_none() {
+                } else if ch == '\t' {
+                    indent_level += 4;
+                    self.advance();
+                } else {
                     break;
                 }
-                
-                self.handle_indentation(indent_level);
-                self.at_line_start = false;
-                continue;
             }
-            
-            let ch = match self.current_char() {
-                Some(c) => c,
-                None => break,
-            };
-            
-            if ch == ' ' || ch == '\t' || ch == '\r' {
-                self.skip_whitespace(false);
-                continue;
-            }
-            
-            if ch == '#' {
+
+            if self.current_char() == Some('#') {
                 self.skip_comment();
-                continue;
+                return Ok(());
             }
-            
-            if ch == '\n' {
-                self.tokens.push(Token::simple(TokenType::Newline, self.line, self.column));
+
+            if self.current_char() == Some('\n') {
                 self.advance();
-                continue;
-            }
-            
-            if ch == '"' || ch == '\'' {
-                let line = self.line;
-                let column = self.column;
-                let string_val = self.read_string()?;
-                self.tokens.push(Token::new(
-                    TokenType::String,
-                    TokenValue::String(string_val),
-                    line,
-                    column,
-                ));
-                continue;
+                return Ok(());
             }
-            
-            if ch.is_ascii_digit() {
-                let line = self.line;
-                let column = self.column;
-                let (number, has_dot) = self.read_number();
-                
-                if has_dot {
-                    self.tokens.push(Token::new(TokenType::Number, TokenValue::Number(number), line, column));
-                } else {
-                    self.tokens.push(Token::new(TokenType::Number, TokenValue::Integer(number as i64), line, column));
-                }
-                continue;
-            }
-            
-            if ch.is_alphabetic() || ch == '_' {
-                let line = self.line;
-                let column = self.column;
-                let identifier = self.read_identifier();
-                let token = self.get_keyword_token(&identifier, line, column);
-                self.tokens.push(token);
-                continue;
+
+            if self.current_char().is_none() {
+                self.finish();
+                return Ok(());
             }
-            
-            let line = self.line;
-            let column = self.column;
-            
-            match ch {
-                '+' => {
-                    self.advance();
-                    self.tokens.push(Token::simple(TokenType::Plus, line, column));
-                },
-                '-' => {
-                    self.advance();
-                    self.tokens.push(Token::simple(TokenType::Minus, line, column));
-                },
-                '*' => {
-                    self.advance();
-                    if self.current_char() == Some('*') {
-                        self.advance();
-                        self.tokens.push(Token::simple(TokenType::Power, line, column));
-                    } else {
-                        self.tokens.push(Token::simple(TokenType::Multiply, line, column));
-                    }
-                },
-                '/' => {
-                    self.advance();
-                    if self.current_char() == Some('/') {
-                        self.advance();
-                        self.tokens.push(Token::simple(TokenType::FloorDiv, line, column));
-                    } else {
-                        self.tokens.push(Token::simple(TokenType::Divide, line, column));
+
+            self.handle_indentation(indent_level);
+            self.at_line_start = false;
+            return Ok(());
+        }
+
+        let ch = match self.current_char() {
+            Some(c) => c,
+            None => {
+                self.finish();
+                return Ok(());
+            },
+        };
+
+        if ch == ' ' || ch == '\t' || ch == '\r' {
+            self.skip_whitespace(false);
+            return Ok(());
+        }
+
+        if ch == '#' {
+            self.skip_comment();
+            return Ok(());
+        }
+
+        if ch == '\n' {
+            let start = self.start_token();
+            self.advance();
+            self.push_token(Token::simple(TokenType::Newline, self.span_from(start)));
+            return Ok(());
+        }
+
+        if ch == '"' {
+            let start = self.start_token();
+            match self.read_string()? {
+                mut segments if segments.len() == 1 => {
+                    match segments.remove(0) {
+                        InterpolationSegment::Literal(symbol) => {
+                            self.push_token(Token::new(
+                                TokenType::String,
+                                TokenValue::String(symbol),
+                                self.span_from(start),
+                            ));
+                        },
+                        InterpolationSegment::Expr(_) => unreachable!(),
                     }
                 },
-                '%' => {
-                    self.advance();
-                    self.tokens.push(Token::simple(TokenType::Modulo, line, column));
+                segments => {
+                    self.push_token(Token::new(
+                        TokenType::InterpolatedString,
+                        TokenValue::Interpolation(segments),
+                        self.span_from(start),
+                    ));
                 },
-                '=' => {
-                    self.advance();
-                    if self.current_char() == Some('=') {
-                        self.advance();
-                        self.tokens.push(Token::simple(TokenType::Equal, line, column));
-                    } else {
-                        self.tokens.push(Token::simple(TokenType::Assign, line, column));
-                    }
-                },
-                '!' => {
+            }
+            return Ok(());
+        }
+
+        if ch == '\'' {
+            let start = self.start_token();
+            let value = self.read_char()?;
+            self.push_token(Token::new(TokenType::Char, TokenValue::Char(value), self.span_from(start)));
+            return Ok(());
+        }
+
+        if ch.is_ascii_digit() {
+            let start = self.start_token();
+            let (number, is_float) = self.read_number()?;
+            let span = self.span_from(start);
+
+            if is_float {
+                self.push_token(Token::new(TokenType::Number, TokenValue::Number(number), span));
+            } else {
+                self.push_token(Token::new(TokenType::Number, TokenValue::Integer(number as i64), span));
+            }
+            return Ok(());
+        }
+
+        if ch.is_alphabetic() || ch == '_' {
+            let start = self.start_token();
+            let identifier = self.read_identifier();
+            let token = self.get_keyword_token(&identifier, start);
+            self.push_token(token);
+            return Ok(());
+        }
+
+        let start = self.start_token();
+
+        match ch {
+            '+' => {
+                self.advance();
+                if self.current_char() == Some('=') {
                     self.advance();
-                    if self.current_char() == Some('=') {
-                        self.advance();
-                        self.tokens.push(Token::simple(TokenType::NotEqual, line, column));
-                    }
-                },
-                '<' => {
+                    self.push_token(Token::simple(TokenType::PlusAssign, self.span_from(start)));
+                } else {
+                    self.push_token(Token::simple(TokenType::Plus, self.span_from(start)));
+                }
+            },
+            '-' => {
+                self.advance();
+                if self.current_char() == Some('=') {
                     self.advance();
-                    if self.current_char() == Some('=') {
-                        self.advance();
-                        self.tokens.push(Token::simple(TokenType::LessEqual, line, column));
-                    } else {
-                        self.tokens.push(Token::simple(TokenType::LessThan, line, column));
-                    }
-                },
-                '>' => {
+                    self.push_token(Token::simple(TokenType::MinusAssign, self.span_from(start)));
+                } else {
+                    self.push_token(Token::simple(TokenType::Minus, self.span_from(start)));
+                }
+            },
+            '*' => {
+                self.advance();
+                if self.current_char() == Some('*') {
                     self.advance();
-                    if self.current_char() == Some('=') {
-                        self.advance();
-                        self.tokens.push(Token::simple(TokenType::GreaterEqual, line, column));
-                    } else {
-                        self.tokens.push(Token::simple(TokenType::GreaterThan, line, column));
-                    }
-                },
-                ':' => {
+                    self.push_token(Token::simple(TokenType::Power, self.span_from(start)));
+                } else if self.current_char() == Some('=') {
                     self.advance();
-                    self.tokens.push(Token::simple(TokenType::Colon, line, column));
-                },
-                ',' => {
+                    self.push_token(Token::simple(TokenType::MultiplyAssign, self.span_from(start)));
+                } else {
+                    self.push_token(Token::simple(TokenType::Multiply, self.span_from(start)));
+                }
+            },
+            '/' => {
+                self.advance();
+                if self.current_char() == Some('/') {
                     self.advance();
-                    self.tokens.push(Token::simple(TokenType::Comma, line, column));
-                },
-                '(' => {
+                    self.push_token(Token::simple(TokenType::FloorDiv, self.span_from(start)));
+                } else if self.current_char() == Some('=') {
                     self.advance();
-                    self.tokens.push(Token::simple(TokenType::LParen, line, column));
-                },
-                ')' => {
+                    self.push_token(Token::simple(TokenType::DivideAssign, self.span_from(start)));
+                } else {
+                    self.push_token(Token::simple(TokenType::Divide, self.span_from(start)));
+                }
+            },
+            '%' => {
+                self.advance();
+                self.push_token(Token::simple(TokenType::Modulo, self.span_from(start)));
+            },
+            '=' => {
+                self.advance();
+                if self.current_char() == Some('=') {
                     self.advance();
-                    self.tokens.push(Token::simple(TokenType::RParen, line, column));
-                },
-                '[' => {
+                    self.push_token(Token::simple(TokenType::Equal, self.span_from(start)));
+                } else {
+                    self.push_token(Token::simple(TokenType::Assign, self.span_from(start)));
+                }
+            },
+            '!' => {
+                self.advance();
+                if self.current_char() == Some('=') {
                     self.advance();
-                    self.tokens.push(Token::simple(TokenType::LBracket, line, column));
-                },
-                ']' => {
+                    self.push_token(Token::simple(TokenType::NotEqual, self.span_from(start)));
+                }
+            },
+            '<' => {
+                self.advance();
+                if self.current_char() == Some('=') {
                     self.advance();
-                    self.tokens.push(Token::simple(TokenType::RBracket, line, column));
-                },
-                '.' => {
+                    self.push_token(Token::simple(TokenType::LessEqual, self.span_from(start)));
+                } else {
+                    self.push_token(Token::simple(TokenType::LessThan, self.span_from(start)));
+                }
+            },
+            '>' => {
+                self.advance();
+                if self.current_char() == Some('=') {
                     self.advance();
-                    self.tokens.push(Token::simple(TokenType::Dot, line, column));
-                },
-                _ => {
-                    return Err(MeowLangError::new(
-                        ErrorCatalog::get("E100"),
-                        self.filename.clone(),
-                        line,
-                        column,
-                    ).with_instruction(ch.to_string()).with_context(&self.lines));
+                    self.push_token(Token::simple(TokenType::GreaterEqual, self.span_from(start)));
+                } else {
+                    self.push_token(Token::simple(TokenType::GreaterThan, self.span_from(start)));
                 }
+            },
+            ':' => {
+                self.advance();
+                self.push_token(Token::simple(TokenType::Colon, self.span_from(start)));
+            },
+            ',' => {
+                self.advance();
+                self.push_token(Token::simple(TokenType::Comma, self.span_from(start)));
+            },
+            '(' => {
+                self.advance();
+                self.push_token(Token::simple(TokenType::LParen, self.span_from(start)));
+            },
+            ')' => {
+                self.advance();
+                self.push_token(Token::simple(TokenType::RParen, self.span_from(start)));
+            },
+            '[' => {
+                self.advance();
+                self.push_token(Token::simple(TokenType::LBracket, self.span_from(start)));
+            },
+            ']' => {
+                self.advance();
+                self.push_token(Token::simple(TokenType::RBracket, self.span_from(start)));
+            },
+            '.' => {
+                self.advance();
+                self.push_token(Token::simple(TokenType::Dot, self.span_from(start)));
+            },
+            '|' => {
+                self.advance();
+                match self.current_char() {
+                    Some('>') => {
+                        self.advance();
+                        self.push_token(Token::simple(TokenType::PipeApply, self.span_from(start)));
+                    },
+                    Some(':') => {
+                        self.advance();
+                        self.push_token(Token::simple(TokenType::PipeMap, self.span_from(start)));
+                    },
+                    Some('?') => {
+                        self.advance();
+                        self.push_token(Token::simple(TokenType::PipeFilter, self.span_from(start)));
+                    },
+                    Some('&') => {
+                        self.advance();
+                        self.push_token(Token::simple(TokenType::PipeZip, self.span_from(start)));
+                    },
+                    _ => {
+                        return Err(MeowLangError::new(
+                            ErrorCatalog::get("E100"),
+                            self.filename.clone(),
+                            start.line,
+                            start.column,
+                        ).with_instruction("|".to_string()).with_context(&self.lines));
+                    }
+                }
+            },
+            _ => {
+                let err = MeowLangError::new(
+                    ErrorCatalog::get("E100"),
+                    self.filename.clone(),
+                    start.line,
+                    start.column,
+                ).with_instruction(ch.to_string()).with_context(&self.lines);
+                self.advance();
+                return Err(err);
             }
         }
-        
+
+        Ok(())
+    }
+
+    /// Queues the trailing dedent-flush and `Eof` onto `pending` and marks
+    /// the stream exhausted — the pull-model equivalent of the tail end of
+    /// the old eager `tokenize`'s loop.
+    fn finish(&mut self) {
+        // Same zero-width reasoning as `handle_indentation`: the trailing
+        // dedent flush and `Eof` mark the end of the source, not a range
+        // of it.
+        self.token_byte_start = self.byte_pos;
         while self.indent_stack.len() > 1 {
             self.indent_stack.pop();
-            self.tokens.push(Token::simple(TokenType::Dedent, self.line, self.column));
+            self.push_token(Token::simple(TokenType::Dedent, Span::point(Position::new(self.line, self.column))));
+        }
+
+        self.push_token(Token::simple(TokenType::Eof, Span::point(Position::new(self.line, self.column))));
+        self.finished = true;
+    }
+
+    /// Pulls the next token from the stream, scanning just enough of the
+    /// source to produce it (see `scan_one`) rather than lexing everything
+    /// up front. Mirrors `tokenize`'s recovery behavior: a lexing error
+    /// doesn't end the stream, it's simply handed back for this one call —
+    /// the next call picks up scanning right after it, same as `tokenize`
+    /// historically recorded the error and kept going. Returns `None` once
+    /// the trailing `Eof` has already been produced.
+    pub fn next_token(&mut self) -> Option<Result<Token, MeowLangError>> {
+        loop {
+            if let Some(token) = self.pending.pop_front() {
+                return Some(Ok(token));
+            }
+            if self.finished {
+                return None;
+            }
+            if let Err(err) = self.scan_one() {
+                return Some(Err(err));
+            }
         }
-        
-        self.tokens.push(Token::simple(TokenType::Eof, self.line, self.column));
-        
-        Ok(self.tokens.clone())
+    }
+
+    /// Eagerly drives `next_token` to completion, collecting every token
+    /// and every error into their own `Vec`s rather than stopping at the
+    /// first problem — scanning itself already recovers from a bad string
+    /// or an unknown character (see `scan_one`'s `Err` arms: each one
+    /// still advances past the offending text before returning), so the
+    /// only thing this adds over a single `next_token` call in a loop is
+    /// collecting both streams. This is the one piece `tokenize` (below)
+    /// used to do differently: on any error it discarded every token
+    /// collected so far along with it. A caller doing its own recovery
+    /// pass — the parser's `DiagnosticSink`, say — wants the (possibly
+    /// imperfect) tokens it got even when the lexer also had complaints,
+    /// which is exactly what comes back here instead.
+    pub fn tokenize_recover(&mut self) -> (Vec<Token>, Vec<MeowLangError>) {
+        let mut tokens = Vec::new();
+        let mut errors = Vec::new();
+
+        while let Some(result) = self.next_token() {
+            match result {
+                Ok(token) => tokens.push(token),
+                Err(err) => errors.push(err),
+            }
+        }
+
+        (tokens, errors)
+    }
+
+    /// The "fail if anything went wrong" wrapper most callers
+    /// (`run`/`run_file`, `parse`) actually want: same scan as
+    /// `tokenize_recover`, but the tokens are thrown away in favor of
+    /// `Err` the moment there's any error to report.
+    pub fn tokenize(&mut self) -> Result<Vec<Token>, Vec<MeowLangError>> {
+        let (tokens, errors) = self.tokenize_recover();
+        if errors.is_empty() {
+            Ok(tokens)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Result<Token, MeowLangError>;
+
+    /// Same as `next_token` — this just lets a `Lexer` be driven with
+    /// ordinary `Iterator` combinators (`for token in &mut lexer`, etc.)
+    /// instead of calling `next_token` by hand.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_token()
     }
 }
@@ -1,41 +1,383 @@
 pub mod token;
 pub mod error;
+pub mod interner;
 pub mod lexer;
 pub mod ast;
 pub mod parser;
-pub mod interpreter;
+pub mod analyzer;
+pub mod compiler;
+pub mod native;
+pub mod vm;
+pub mod codegen;
+pub mod optimizer;
+pub mod resolver;
+pub mod ast_json;
+pub mod builtins;
+pub mod formatter;
+pub mod lsp;
 
 use std::fs;
+use std::io::{self, Write};
+use std::sync::Arc;
 use lexer::Lexer;
 use parser::Parser;
-use interpreter::Interpreter;
+use analyzer::Analyzer;
+use resolver::Resolver;
+use compiler::Compiler;
+use vm::{Value, VM};
+use interner::{Interner, Symbol};
+use ast::{ASTNode, LiteralValue, Position, Span};
 use error::MeowLangError;
+use token::Token;
+use codegen::Target;
 
-pub fn run_file(filename: &str) -> Result<(), MeowLangError> {
+/// An alias for the public front-end functions below, so callers that only
+/// want to inspect a parse tree don't need to reach into `ast::ASTNode`
+/// directly.
+pub type Ast = ASTNode;
+
+/// Flags controlling how far `run`/`run_file` carry a source file through
+/// the pipeline, and what intermediate stages they print along the way —
+/// see [`lex`] and [`parse`] for reusing a single stage standalone instead.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    /// Print every token the lexer produced, in source order.
+    pub show_tokens: bool,
+    /// Pretty-print the parsed AST.
+    pub show_ast: bool,
+    /// Stop after parsing — lex and parse only, no analysis or execution.
+    /// Lets tools validate syntax without running anything or requiring
+    /// the program to be semantically complete.
+    pub check_only: bool,
+    /// Print the token stream as JSON and exit before parsing — see
+    /// [`ast_json::tokens_to_json`]. Unlike `show_tokens`, this always
+    /// exits instead of falling through to the rest of the pipeline.
+    pub dump_tokens: bool,
+    /// Print the parsed (and constant-folded) AST as JSON and exit before
+    /// analysis — see [`ast_json::ast_to_json`]. Unlike `show_ast`, this
+    /// always exits instead of falling through to the rest of the
+    /// pipeline.
+    pub dump_ast: bool,
+    /// Print `source` reformatted by `meowfmt` and exit before analysis —
+    /// see [`formatter::format`]. Like `dump_ast`, runs on the already
+    /// constant-folded AST and always exits instead of falling through.
+    pub format: bool,
+    /// When set to anything other than `Target::Interpret`, `run` emits
+    /// source in that language to `output_path` instead of compiling to
+    /// bytecode and handing it to the VM — see [`codegen`].
+    pub target: Target,
+    /// Where to write the emitted source when `target` isn't
+    /// `Target::Interpret`. Ignored otherwise; `run` defaults to `"out"`
+    /// if a target is set but this is left unset.
+    pub output_path: Option<String>,
+}
+
+/// Lexes `source` on its own, outside the `run`/`run_file` pipeline, for
+/// tooling that only wants the token stream (an editor's syntax
+/// highlighter, for instance). Uses a throwaway `Interner`, so `Symbol`s in
+/// the returned tokens aren't comparable against any other pipeline run.
+pub fn lex(source: &str, filename: &str) -> Result<Vec<Token>, MeowLangError> {
+    let mut interner = Interner::new();
+    let mut lexer = Lexer::new(source.to_string(), filename.to_string(), &mut interner);
+    lexer.tokenize().map_err(|mut errors| errors.remove(0))
+}
+
+/// Parses `source` on its own, outside the `run`/`run_file` pipeline, for
+/// tooling that only wants the AST (a formatter, a linter). The parser may
+/// collect several diagnostics in one pass (see `DiagnosticSink`); this
+/// standalone entry point only surfaces the first one; `run`/`run_file`
+/// report the full set.
+pub fn parse(source: &str, filename: &str) -> Result<Ast, MeowLangError> {
+    let mut interner = Interner::new();
+    // Can't delegate to `lex` here: it interns into its own throwaway
+    // `Interner` that's dropped before returning, and the `Symbol`s this
+    // parse needs to `resolve` (identifiers, string literals) have to come
+    // from the same `Interner` the tokens were lexed with.
+    let mut lexer = Lexer::new(source.to_string(), filename.to_string(), &mut interner);
+    let tokens = lexer.tokenize().map_err(|mut errors| errors.remove(0))?;
+    let source_lines: Vec<String> = source.lines().map(|s| s.to_string()).collect();
+    let mut parser = Parser::new(tokens, filename.to_string(), source_lines, &mut interner);
+    parser.parse().map_err(|mut errors| errors.remove(0))
+}
+
+pub fn run_file(filename: &str, options: &RunOptions) -> Result<(), Vec<MeowLangError>> {
     let source = fs::read_to_string(filename).map_err(|_| {
-        MeowLangError::new(
+        vec![MeowLangError::new(
             error::ErrorCatalog::get("E900"),
             filename.to_string(),
             1,
             1,
         )
-        .with_extra("filename".to_string(), filename.to_string())
+        .with_extra("filename".to_string(), filename.to_string())]
     })?;
-    
-    run(source, filename.to_string())
+
+    run(source, filename.to_string(), options)
 }
 
-pub fn run(source: String, filename: String) -> Result<(), MeowLangError> {
+/// Runs a MeowLang source file. Both the lexer and the parser collect as
+/// many diagnostics as they can in one pass (the parser via
+/// `DiagnosticSink`, the lexer by skipping the offending character/string
+/// and carrying on), so a failure here may carry several errors rather
+/// than just the first one encountered. The parsed AST is then constant-
+/// folded once by `optimizer::optimize` before anything else sees it, so
+/// `show_ast` and every later stage already observe the folded tree. After
+/// that, the `Analyzer` walks the AST once more before anything runs, so a
+/// mistake in a branch that never executes this time still gets reported.
+/// `Resolver` then walks it a third time, in source order, catching what
+/// `Analyzer`'s single unordered pass can't (a variable read before it's
+/// ever assigned, `compteur` used outside a `Repeter` body) and printing
+/// (not failing on) unused-local warnings. Only once all three passes are
+/// clean is the AST compiled to bytecode and handed to the VM rather than
+/// walked directly — unless `options.target` asks for an ahead-of-time
+/// target instead, in which case the AST is emitted as source via
+/// [`codegen::emit_source`] and written to `options.output_path` rather
+/// than compiled and run. `options` can also stop the pipeline early
+/// (`check_only`, `dump_tokens`/`dump_ast`, which print structured JSON
+/// for external tooling instead of the `Debug`-formatted dumps
+/// `show_tokens`/`show_ast` print, or `format`, which prints the
+/// `meowfmt`-canonicalized source) — see [`RunOptions`].
+pub fn run(source: String, filename: String, options: &RunOptions) -> Result<(), Vec<MeowLangError>> {
     let source_lines: Vec<String> = source.lines().map(|s| s.to_string()).collect();
-    
-    let mut lexer = Lexer::new(source.clone(), filename.clone());
+    let mut interner = Interner::new();
+
+    let mut lexer = Lexer::new(source.clone(), filename.clone(), &mut interner);
     let tokens = lexer.tokenize()?;
-    
-    let mut parser = Parser::new(tokens, filename.clone(), source_lines.clone());
+
+    if options.show_tokens {
+        for token in &tokens {
+            println!("{:?}", token);
+        }
+    }
+
+    if options.dump_tokens {
+        println!("{}", ast_json::tokens_to_json(&tokens, &interner));
+        return Ok(());
+    }
+
+    let mut parser = Parser::new(tokens, filename.clone(), source_lines.clone(), &mut interner);
     let ast = parser.parse()?;
-    
-    let mut interpreter = Interpreter::new(filename, source_lines);
-    interpreter.execute(&ast)?;
-    
+    let ast = optimizer::optimize(ast, &mut interner).map_err(|e| vec![e])?;
+
+    if options.show_ast {
+        println!("{:#?}", ast);
+    }
+
+    if options.dump_ast {
+        println!("{}", ast_json::ast_to_json(&ast, &interner));
+        return Ok(());
+    }
+
+    if options.format {
+        print!("{}", formatter::format(&ast, &interner));
+        return Ok(());
+    }
+
+    if options.check_only {
+        return Ok(());
+    }
+
+    let analyzer_errors = Analyzer::new(&interner, filename.clone(), source_lines.clone()).analyze(&ast);
+    if !analyzer_errors.is_empty() {
+        return Err(analyzer_errors);
+    }
+
+    let (resolver_errors, resolver_warnings) =
+        Resolver::new(&mut interner, filename.clone(), source_lines.clone()).resolve(&ast);
+    for warning in &resolver_warnings {
+        eprintln!("{}", warning);
+    }
+    if !resolver_errors.is_empty() {
+        return Err(resolver_errors);
+    }
+
+    if options.target != Target::Interpret {
+        let output_path = options.output_path.as_deref().unwrap_or("out");
+        let emitted = codegen::emit_source(&ast, &interner, options.target);
+        return fs::write(output_path, emitted).map_err(|e| {
+            vec![MeowLangError::new(error::ErrorCatalog::get("E901"), filename.clone(), 1, 1)
+                .with_extra("filename".to_string(), output_path.to_string())
+                .with_extra("reason".to_string(), e.to_string())]
+        });
+    }
+
+    let program = Arc::new(Compiler::new(&mut interner).compile(&ast));
+    let mut vm = VM::new(&program, Arc::clone(&program), interner, filename, source_lines);
+    vm.run().map_err(|e| vec![e])?;
+
     Ok(())
 }
+
+/// A persistent read-eval-print loop: each line (or continuation block) is
+/// lexed, parsed, analyzed and compiled to its own small `CompiledProgram`
+/// and run on a fresh `VM`, but variables and function definitions survive
+/// from one turn to the next. There's no incremental-compilation story in
+/// this pipeline — `Compiler::compile` always consumes a whole program —
+/// so continuity is faked at the source level instead: previously seen
+/// `FunctionDef`s and the prior turn's bound globals (replayed as plain
+/// assignments, not re-executed, so a turn that printed something doesn't
+/// print it again) are prepended to each new turn's statements before it
+/// compiles. The same `Interner` is threaded through every turn via
+/// `VM::into_interner` so `Symbol`s stay stable across turns.
+///
+/// `Value::Rational`/`Value::Complex` globals are carried forward as their
+/// closest real-number approximation, since literal syntax has no exact
+/// form for either to round-trip a synthesized assignment through.
+pub fn run_repl() {
+    println!("MeowLang REPL — \"quitter\" ou Ctrl+D pour partir.");
+
+    let mut interner = Interner::new();
+    let mut globals: Vec<(Symbol, Value)> = Vec::new();
+    let mut function_defs: Vec<ASTNode> = Vec::new();
+    let point = Position::new(1, 1);
+
+    loop {
+        let mut buffer = String::new();
+        print!("meow> ");
+        let _ = io::stdout().flush();
+        if io::stdin().read_line(&mut buffer).unwrap_or(0) == 0 {
+            println!();
+            break;
+        }
+        if buffer.trim().is_empty() {
+            continue;
+        }
+        if buffer.trim() == "quitter" {
+            break;
+        }
+
+        while needs_continuation(&buffer) {
+            print!("...   ");
+            let _ = io::stdout().flush();
+            let mut more = String::new();
+            if io::stdin().read_line(&mut more).unwrap_or(0) == 0 {
+                break;
+            }
+            buffer.push_str(&more);
+        }
+
+        let filename = "<repl>".to_string();
+        let wrapped = format!("miaou\n{}\nmeow\n", buffer);
+        let source_lines: Vec<String> = wrapped.lines().map(|s| s.to_string()).collect();
+
+        let mut lexer = Lexer::new(wrapped.clone(), filename.clone(), &mut interner);
+        let tokens = match lexer.tokenize() {
+            Ok(tokens) => tokens,
+            Err(errors) => { report_errors(&errors); continue; },
+        };
+
+        let mut parser = Parser::new(tokens, filename.clone(), source_lines.clone(), &mut interner);
+        let mut ast = match parser.parse() {
+            Ok(ast) => ast,
+            Err(errors) => { report_errors(&errors); continue; },
+        };
+
+        let statements = match &mut ast {
+            ASTNode::Program { statements, .. } => statements,
+            _ => unreachable!("parser always produces ASTNode::Program at the root"),
+        };
+
+        let new_funcs: Vec<ASTNode> = statements.iter()
+            .filter(|s| matches!(s, ASTNode::FunctionDef { .. }))
+            .cloned()
+            .collect();
+
+        let mut preamble: Vec<ASTNode> = function_defs.clone();
+        for (name, value) in &globals {
+            preamble.push(ASTNode::Assignment {
+                name: *name,
+                value: Box::new(value_to_ast(value, &mut interner, &point)),
+                span: Span::point(point.clone()),
+            });
+        }
+        preamble.append(statements);
+        *statements = preamble;
+        function_defs.extend(new_funcs);
+
+        let analyzer_errors = Analyzer::new(&interner, filename.clone(), source_lines.clone()).analyze(&ast);
+        if !analyzer_errors.is_empty() {
+            report_errors(&analyzer_errors);
+            continue;
+        }
+
+        let (resolver_errors, resolver_warnings) =
+            Resolver::new(&mut interner, filename.clone(), source_lines.clone()).resolve(&ast);
+        for warning in &resolver_warnings {
+            eprintln!("{}", warning);
+        }
+        if !resolver_errors.is_empty() {
+            report_errors(&resolver_errors);
+            continue;
+        }
+
+        let program = Arc::new(Compiler::new(&mut interner).compile(&ast));
+        let mut vm = VM::new(&program, Arc::clone(&program), interner, filename, source_lines);
+        if let Err(e) = vm.run() {
+            report_errors(&[e]);
+        }
+        globals = vm.snapshot_locals();
+        interner = vm.into_interner();
+    }
+}
+
+/// Pragmatic continuation heuristic: this grammar tracks block bodies by
+/// indentation (`Indent`/`Dedent` tokens from the lexer), and its parser
+/// has no error variant that distinguishes "incomplete" input from
+/// "malformed" input — panic-mode recovery treats both as plain
+/// diagnostics (see `Parser::synchronize`). Rather than catch a
+/// distinction that doesn't exist, keep reading lines while brackets are
+/// unbalanced or the last non-blank line opens a block (ends in `:`, per
+/// `si ... alors:`/`tant que ...:`/etc.). A pasted multi-line block whose
+/// body lines don't themselves end in `:` still needs its blank trailing
+/// line to close it, same as the block syntax normally requires.
+fn needs_continuation(buffer: &str) -> bool {
+    let mut depth = 0i32;
+    for c in buffer.chars() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            _ => {},
+        }
+    }
+    if depth > 0 {
+        return true;
+    }
+    buffer.lines().rev().find(|l| !l.trim().is_empty())
+        .map(|l| l.trim_end().ends_with(':'))
+        .unwrap_or(false)
+}
+
+fn report_errors(errors: &[MeowLangError]) {
+    for error in errors {
+        eprintln!("{}", error);
+    }
+}
+
+/// Reconstructs a `Value` bound in a previous turn as the literal-valued
+/// AST node an assignment needs to replay it, without re-running whatever
+/// expression produced it in the first place.
+fn value_to_ast(value: &Value, interner: &mut Interner, position: &Position) -> ASTNode {
+    let span = Span::point(position.clone());
+    match value {
+        Value::String(s) => ASTNode::Literal { value: LiteralValue::String(interner.intern(s)), span },
+        Value::Number(n) => ASTNode::Literal { value: LiteralValue::Number(*n), span },
+        Value::Integer(i) => ASTNode::Literal { value: LiteralValue::Integer(*i), span },
+        Value::Boolean(b) => ASTNode::Literal { value: LiteralValue::Boolean(*b), span },
+        Value::None => ASTNode::Literal { value: LiteralValue::None, span },
+        Value::Rational { num, den } => {
+            ASTNode::Literal { value: LiteralValue::Number(*num as f64 / *den as f64), span }
+        },
+        Value::Complex { re, .. } => ASTNode::Literal { value: LiteralValue::Number(*re), span },
+        Value::List(items) => ASTNode::ListNode {
+            elements: items.iter().map(|v| value_to_ast(v, interner, position)).collect(),
+            span,
+            leading_trivia: Vec::new(),
+        },
+        Value::Dict(pairs) => ASTNode::DictNode {
+            pairs: pairs.iter().map(|(k, v)| {
+                let key = ASTNode::Literal { value: LiteralValue::String(interner.intern(k)), span: Span::point(position.clone()) };
+                (key, value_to_ast(v, interner, position))
+            }).collect(),
+            span,
+        },
+    }
+}
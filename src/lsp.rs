@@ -0,0 +1,547 @@
+//! A minimal Language Server Protocol front end over `Lexer`/`Parser`,
+//! driven by `meowlang --lsp` (see `main.rs`). Speaks JSON-RPC 2.0 framed
+//! with `Content-Length` headers over stdin/stdout, same as any other
+//! LSP server.
+//!
+//! Scope: `initialize`, `textDocument/didOpen`/`didChange`/`didClose`
+//! (full document sync — each change replaces the previous text outright
+//! rather than applying incremental range edits; "incremental" here
+//! means only the one file that changed gets re-lexed, not the whole
+//! workspace, which is a smaller claim than LSP's incremental *sync*),
+//! `textDocument/semanticTokens/full`, and `shutdown`/`exit`. Diagnostics
+//! come from `Lexer::tokenize_recover` (chunk6-2) and `Parser::parse`'s
+//! `Vec<MeowLangError>` (`DiagnosticSink`, chunk0-3), translated from
+//! this crate's 1-based line/column positions to LSP's 0-based ones.
+//!
+//! There's no `serde`/`serde_json` here (no `Cargo.toml` to hang them
+//! off, same constraint `ast_json`'s module doc comment explains):
+//! outgoing messages are simple enough to hand-build with `format!` and
+//! `error::json_string`, exactly how `error.rs`/`ast_json.rs` already
+//! produce JSON; incoming messages are arbitrary editor-supplied
+//! structures, so parsing them does need a real (if small) JSON reader.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+use crate::error::{json_string, ErrorSeverity, MeowLangError};
+use crate::interner::Interner;
+use crate::lexer::Lexer;
+use crate::parser::Parser;
+use crate::token::TokenType;
+
+/// The semantic token kinds this server reports, in legend order — the
+/// index of a variant's match arm in `semantic_token_kind` below is the
+/// integer each token in `semanticTokens/full`'s data array refers back
+/// to this table by.
+const SEMANTIC_TOKEN_LEGEND: &[&str] = &["keyword", "string", "number", "operator", "variable"];
+
+/// Maps a `TokenType` to an index into `SEMANTIC_TOKEN_LEGEND`, or `None`
+/// for token kinds not worth a semantic highlight (punctuation, layout
+/// tokens) — left to the editor's default foreground the same way most
+/// semantic token providers skip punctuation entirely.
+fn semantic_token_kind(token_type: &TokenType) -> Option<u32> {
+    use TokenType::*;
+    let index = match token_type {
+        Miaou | Meow | Ecrire | Demander | Texte | Nombre | Si | Alors | Sinon | SinonSi
+        | Repeter | Fois | TantQue | PourChaque | Dans | Compteur | Fonction | Retour
+        | Casser | Continuer | Liste | Dictionnaire | Essayer | Sauf | Erreur | Comme
+        | Importer | Ouvrir | Lire | Fermer | Et | Ou | Non | A | Boolean => 0,
+        String | InterpolatedString | Char => 1,
+        Number => 2,
+        Plus | Minus | Multiply | Divide | FloorDiv | Modulo | Power | Assign | Equal
+        | NotEqual | LessThan | GreaterThan | LessEqual | GreaterEqual | PlusAssign
+        | MinusAssign | MultiplyAssign | DivideAssign | PipeApply | PipeMap | PipeFilter
+        | PipeZip => 3,
+        Identifier => 4,
+        _ => return None,
+    };
+    Some(index)
+}
+
+/// A hand-rolled JSON value — just enough to pull fields back out of
+/// whatever an editor sends (see the module doc comment for why there's
+/// no `serde_json`).
+#[derive(Debug, Clone)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(pairs) => pairs.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Re-serializes this value for the wire — used only to echo a
+    /// request's `id` back in its response, since that can legally be a
+    /// string, a number, or `null`. Every outgoing message body otherwise
+    /// built by hand with `format!`, same as the rest of this module.
+    fn to_wire(&self) -> String {
+        match self {
+            Json::Null => "null".to_string(),
+            Json::Bool(b) => b.to_string(),
+            Json::Number(n) if n.fract() == 0.0 => format!("{}", *n as i64),
+            Json::Number(n) => n.to_string(),
+            Json::String(s) => json_string(s),
+            Json::Array(items) => format!("[{}]", items.iter().map(Json::to_wire).collect::<Vec<_>>().join(",")),
+            Json::Object(pairs) => format!(
+                "{{{}}}",
+                pairs.iter().map(|(k, v)| format!("{}:{}", json_string(k), v.to_wire())).collect::<Vec<_>>().join(",")
+            ),
+        }
+    }
+}
+
+fn skip_ws(chars: &[char], pos: &mut usize) {
+    while matches!(chars.get(*pos), Some(c) if c.is_whitespace()) {
+        *pos += 1;
+    }
+}
+
+fn expect_literal(chars: &[char], pos: &mut usize, literal: &str) -> Option<()> {
+    let end = *pos + literal.chars().count();
+    let slice: String = chars.get(*pos..end)?.iter().collect();
+    if slice == literal {
+        *pos = end;
+        Some(())
+    } else {
+        None
+    }
+}
+
+fn parse_json_string(chars: &[char], pos: &mut usize) -> Option<String> {
+    if chars.get(*pos) != Some(&'"') {
+        return None;
+    }
+    *pos += 1;
+    let mut out = String::new();
+    loop {
+        let ch = *chars.get(*pos)?;
+        *pos += 1;
+        match ch {
+            '"' => break,
+            '\\' => {
+                let escaped = *chars.get(*pos)?;
+                *pos += 1;
+                match escaped {
+                    '"' => out.push('"'),
+                    '\\' => out.push('\\'),
+                    '/' => out.push('/'),
+                    'n' => out.push('\n'),
+                    't' => out.push('\t'),
+                    'r' => out.push('\r'),
+                    'b' => out.push('\u{8}'),
+                    'f' => out.push('\u{c}'),
+                    'u' => {
+                        let hex: String = chars.get(*pos..*pos + 4)?.iter().collect();
+                        *pos += 4;
+                        let code = u32::from_str_radix(&hex, 16).ok()?;
+                        out.push(char::from_u32(code)?);
+                    },
+                    other => out.push(other),
+                }
+            },
+            other => out.push(other),
+        }
+    }
+    Some(out)
+}
+
+fn parse_json_number(chars: &[char], pos: &mut usize) -> Option<f64> {
+    let start = *pos;
+    if chars.get(*pos) == Some(&'-') {
+        *pos += 1;
+    }
+    while matches!(chars.get(*pos), Some(c) if c.is_ascii_digit()) {
+        *pos += 1;
+    }
+    if chars.get(*pos) == Some(&'.') {
+        *pos += 1;
+        while matches!(chars.get(*pos), Some(c) if c.is_ascii_digit()) {
+            *pos += 1;
+        }
+    }
+    if matches!(chars.get(*pos), Some('e') | Some('E')) {
+        *pos += 1;
+        if matches!(chars.get(*pos), Some('+') | Some('-')) {
+            *pos += 1;
+        }
+        while matches!(chars.get(*pos), Some(c) if c.is_ascii_digit()) {
+            *pos += 1;
+        }
+    }
+    if *pos == start {
+        return None;
+    }
+    let text: String = chars[start..*pos].iter().collect();
+    text.parse::<f64>().ok()
+}
+
+fn parse_json_array(chars: &[char], pos: &mut usize) -> Option<Json> {
+    *pos += 1;
+    let mut items = Vec::new();
+    skip_ws(chars, pos);
+    if chars.get(*pos) == Some(&']') {
+        *pos += 1;
+        return Some(Json::Array(items));
+    }
+    loop {
+        items.push(parse_json_value(chars, pos)?);
+        skip_ws(chars, pos);
+        match chars.get(*pos) {
+            Some(',') => {
+                *pos += 1;
+            },
+            Some(']') => {
+                *pos += 1;
+                break;
+            },
+            _ => return None,
+        }
+    }
+    Some(Json::Array(items))
+}
+
+fn parse_json_object(chars: &[char], pos: &mut usize) -> Option<Json> {
+    *pos += 1;
+    let mut pairs = Vec::new();
+    skip_ws(chars, pos);
+    if chars.get(*pos) == Some(&'}') {
+        *pos += 1;
+        return Some(Json::Object(pairs));
+    }
+    loop {
+        skip_ws(chars, pos);
+        let key = parse_json_string(chars, pos)?;
+        skip_ws(chars, pos);
+        if chars.get(*pos) != Some(&':') {
+            return None;
+        }
+        *pos += 1;
+        let value = parse_json_value(chars, pos)?;
+        pairs.push((key, value));
+        skip_ws(chars, pos);
+        match chars.get(*pos) {
+            Some(',') => {
+                *pos += 1;
+            },
+            Some('}') => {
+                *pos += 1;
+                break;
+            },
+            _ => return None,
+        }
+    }
+    Some(Json::Object(pairs))
+}
+
+fn parse_json_value(chars: &[char], pos: &mut usize) -> Option<Json> {
+    skip_ws(chars, pos);
+    match chars.get(*pos)? {
+        '{' => parse_json_object(chars, pos),
+        '[' => parse_json_array(chars, pos),
+        '"' => parse_json_string(chars, pos).map(Json::String),
+        't' => {
+            expect_literal(chars, pos, "true")?;
+            Some(Json::Bool(true))
+        },
+        'f' => {
+            expect_literal(chars, pos, "false")?;
+            Some(Json::Bool(false))
+        },
+        'n' => {
+            expect_literal(chars, pos, "null")?;
+            Some(Json::Null)
+        },
+        _ => parse_json_number(chars, pos).map(Json::Number),
+    }
+}
+
+fn parse_json(input: &str) -> Option<Json> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut pos = 0;
+    parse_json_value(&chars, &mut pos)
+}
+
+/// Reads one `Content-Length`-framed JSON-RPC message body from `reader`.
+/// `Ok(None)` means the client closed the stream; a message with a
+/// missing/malformed header comes back as `Ok(Some(String::new()))` so
+/// the caller can skip it and keep serving the rest of the session
+/// instead of tearing the whole server down over one bad frame.
+fn read_message<R: BufRead>(reader: &mut R) -> io::Result<Option<String>> {
+    let mut content_length: Option<usize> = None;
+
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("Content-Length:") {
+            content_length = value.trim().parse().ok();
+        }
+    }
+
+    let Some(content_length) = content_length else {
+        return Ok(Some(String::new()));
+    };
+
+    let mut buf = vec![0u8; content_length];
+    reader.read_exact(&mut buf)?;
+    Ok(Some(String::from_utf8_lossy(&buf).into_owned()))
+}
+
+fn send<W: Write>(writer: &mut W, body: String) {
+    let _ = write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body);
+    let _ = writer.flush();
+}
+
+fn respond<W: Write>(writer: &mut W, id: &Json, result: &str) {
+    send(writer, format!("{{\"jsonrpc\":\"2.0\",\"id\":{},\"result\":{}}}", id.to_wire(), result));
+}
+
+fn respond_error<W: Write>(writer: &mut W, id: &Json, code: i32, message: &str) {
+    send(
+        writer,
+        format!(
+            "{{\"jsonrpc\":\"2.0\",\"id\":{},\"error\":{{\"code\":{},\"message\":{}}}}}",
+            id.to_wire(),
+            code,
+            json_string(message),
+        ),
+    );
+}
+
+fn notify<W: Write>(writer: &mut W, method: &str, params: String) {
+    send(writer, format!("{{\"jsonrpc\":\"2.0\",\"method\":{},\"params\":{}}}", json_string(method), params));
+}
+
+fn lsp_severity(severity: &ErrorSeverity) -> u8 {
+    match severity {
+        ErrorSeverity::Forte | ErrorSeverity::Moyenne => 1,
+        ErrorSeverity::Faible => 2,
+    }
+}
+
+/// Builds one LSP `Diagnostic` object from a `MeowLangError`, converting
+/// its 1-based `line`/`column` to LSP's 0-based `line`/`character` and
+/// underlining a single character past the error's start (as precise a
+/// range as `MeowLangError` tracks without also threading its `labels`
+/// spans through here).
+fn error_to_diagnostic(error: &MeowLangError) -> String {
+    let line0 = error.line.saturating_sub(1);
+    let col0 = error.column.saturating_sub(1);
+    format!(
+        "{{\"range\":{{\"start\":{{\"line\":{},\"character\":{}}},\"end\":{{\"line\":{},\"character\":{}}}}},\"severity\":{},\"code\":{},\"source\":\"meowlang\",\"message\":{}}}",
+        line0,
+        col0,
+        line0,
+        col0 + 1,
+        lsp_severity(&error.error_def.severity),
+        json_string(error.error_def.code),
+        json_string(&error.message()),
+    )
+}
+
+/// Lexes (in recovery mode) and, if that's clean, parses `source`,
+/// returning whatever diagnostics either stage produced. Mirrors `run`'s
+/// bail-on-lex-errors-before-parsing order in `lib.rs`, except it keeps
+/// every lex error `tokenize_recover` collected instead of just the
+/// first.
+fn collect_diagnostics(source: &str, filename: &str) -> Vec<MeowLangError> {
+    let mut interner = Interner::new();
+    let mut lexer = Lexer::new(source.to_string(), filename.to_string(), &mut interner);
+    let (tokens, errors) = lexer.tokenize_recover();
+    if !errors.is_empty() {
+        return errors;
+    }
+
+    let lines: Vec<String> = source.lines().map(|s| s.to_string()).collect();
+    let mut parser = Parser::new(tokens, filename.to_string(), lines, &mut interner);
+    match parser.parse() {
+        Ok(_) => Vec::new(),
+        Err(errors) => errors,
+    }
+}
+
+fn publish_diagnostics<W: Write>(writer: &mut W, uri: &str, source: &str) {
+    let diagnostics = collect_diagnostics(source, uri);
+    let items: Vec<String> = diagnostics.iter().map(error_to_diagnostic).collect();
+    let params = format!("{{\"uri\":{},\"diagnostics\":[{}]}}", json_string(uri), items.join(","));
+    notify(writer, "textDocument/publishDiagnostics", params);
+}
+
+/// Lexes `source` (best-effort, recovery mode) and flattens its tokens
+/// into LSP's delta-encoded `semanticTokens/full` data array: each token
+/// contributes `[deltaLine, deltaStartChar, length, tokenType,
+/// tokenModifiers]`, relative to the previous reported token. Tokens with
+/// no mapped kind (see `semantic_token_kind`) or that span more than one
+/// source line (semantic tokens can't) are skipped.
+fn semantic_tokens_data(source: &str) -> Vec<String> {
+    let mut interner = Interner::new();
+    let mut lexer = Lexer::new(source.to_string(), "<semantic-tokens>".to_string(), &mut interner);
+    let (tokens, _errors) = lexer.tokenize_recover();
+
+    let mut data = Vec::new();
+    let mut prev_line = 0usize;
+    let mut prev_start = 0usize;
+
+    for token in &tokens {
+        let Some(kind) = semantic_token_kind(&token.token_type) else {
+            continue;
+        };
+        if token.span.start.line != token.span.end.line {
+            continue;
+        }
+        let length = token.span.end.column.saturating_sub(token.span.start.column);
+        if length == 0 {
+            continue;
+        }
+
+        let line0 = token.span.start.line - 1;
+        let start0 = token.span.start.column - 1;
+        let delta_line = line0 - prev_line;
+        let delta_start = if delta_line == 0 { start0 - prev_start } else { start0 };
+
+        data.push(delta_line.to_string());
+        data.push(delta_start.to_string());
+        data.push(length.to_string());
+        data.push(kind.to_string());
+        data.push("0".to_string());
+
+        prev_line = line0;
+        prev_start = start0;
+    }
+
+    data
+}
+
+fn uri_from_text_document(message: &Json) -> Option<String> {
+    message.get("params")?.get("textDocument")?.get("uri")?.as_str().map(str::to_string)
+}
+
+fn opened_document(message: &Json) -> Option<(String, String)> {
+    let doc = message.get("params")?.get("textDocument")?;
+    let uri = doc.get("uri")?.as_str()?.to_string();
+    let text = doc.get("text")?.as_str()?.to_string();
+    Some((uri, text))
+}
+
+/// `textDocumentSync: Full` means a `didChange` always carries the whole
+/// new document as a single `contentChanges` entry — this takes the last
+/// one, matching what every client sends in that mode regardless of how
+/// many entries happen to be in the array.
+fn changed_document_text(message: &Json) -> Option<String> {
+    let changes = message.get("params")?.get("contentChanges")?.as_array()?;
+    changes.last()?.get("text")?.as_str().map(str::to_string)
+}
+
+fn respond_initialize<W: Write>(writer: &mut W, id: &Json) {
+    let legend = SEMANTIC_TOKEN_LEGEND.iter().map(|t| json_string(t)).collect::<Vec<_>>().join(",");
+    let result = format!(
+        "{{\"capabilities\":{{\"textDocumentSync\":1,\"semanticTokensProvider\":{{\"legend\":{{\"tokenTypes\":[{}],\"tokenModifiers\":[]}},\"full\":true}}}}}}",
+        legend
+    );
+    respond(writer, id, &result);
+}
+
+/// Runs the LSP server, reading JSON-RPC requests/notifications from
+/// stdin and writing responses/notifications to stdout until the client
+/// disconnects or sends `exit`. See the module doc comment for what's
+/// implemented.
+pub fn run() {
+    let stdin = io::stdin();
+    let mut reader = stdin.lock();
+    let stdout = io::stdout();
+    let mut writer = stdout.lock();
+
+    let mut documents: HashMap<String, String> = HashMap::new();
+
+    loop {
+        let body = match read_message(&mut reader) {
+            Ok(Some(body)) => body,
+            Ok(None) => break,
+            Err(_) => break,
+        };
+        if body.is_empty() {
+            continue;
+        }
+
+        let Some(message) = parse_json(&body) else {
+            continue;
+        };
+
+        let method = message.get("method").and_then(Json::as_str).unwrap_or("");
+        let id = message.get("id").cloned();
+
+        match method {
+            "initialize" => {
+                if let Some(id) = &id {
+                    respond_initialize(&mut writer, id);
+                }
+            },
+            "initialized" | "$/cancelRequest" => {},
+            "textDocument/didOpen" => {
+                if let Some((uri, text)) = opened_document(&message) {
+                    documents.insert(uri.clone(), text);
+                    publish_diagnostics(&mut writer, &uri, &documents[&uri]);
+                }
+            },
+            "textDocument/didChange" => {
+                if let Some(uri) = uri_from_text_document(&message) {
+                    if let Some(text) = changed_document_text(&message) {
+                        documents.insert(uri.clone(), text);
+                        publish_diagnostics(&mut writer, &uri, &documents[&uri]);
+                    }
+                }
+            },
+            "textDocument/didClose" => {
+                if let Some(uri) = uri_from_text_document(&message) {
+                    documents.remove(&uri);
+                }
+            },
+            "textDocument/semanticTokens/full" => {
+                if let Some(id) = &id {
+                    let uri = uri_from_text_document(&message);
+                    let data = uri.and_then(|uri| documents.get(&uri).map(|text| semantic_tokens_data(text))).unwrap_or_default();
+                    respond(&mut writer, id, &format!("{{\"data\":[{}]}}", data.join(",")));
+                }
+            },
+            "shutdown" => {
+                if let Some(id) = &id {
+                    respond(&mut writer, id, "null");
+                }
+            },
+            "exit" => break,
+            _ => {
+                if let Some(id) = &id {
+                    respond_error(&mut writer, id, -32601, "Method not found");
+                }
+            },
+        }
+    }
+}
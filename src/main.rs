@@ -1,24 +1,65 @@
-use meowlang::run_file;
+use meowlang::codegen::Target;
+use meowlang::{run_file, run_repl, RunOptions};
 use std::env;
 use std::process;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    
-    if args.len() < 2 {
-        eprintln!("🐱 MeowLang - Un langage élégant, félin et francophone");
-        eprintln!();
-        eprintln!("Usage: meowlang <fichier.miaou>");
-        eprintln!();
-        eprintln!("Exemple:");
-        eprintln!("  meowlang hello.miaou");
-        process::exit(1);
+
+    let mut json_format = false;
+    let mut options = RunOptions::default();
+    let mut filename = None;
+
+    if args.iter().any(|arg| arg == "--lsp") {
+        meowlang::lsp::run();
+        return;
     }
-    
-    let filename = &args[1];
-    
-    if let Err(error) = run_file(filename) {
-        eprintln!("{}", error);
+
+    for arg in &args[1..] {
+        match arg.as_str() {
+            "--format=json" => json_format = true,
+            "--show-tokens" => options.show_tokens = true,
+            "--show-ast" => options.show_ast = true,
+            "--check" => options.check_only = true,
+            "--dump-tokens" => options.dump_tokens = true,
+            "--dump-ast" => options.dump_ast = true,
+            "--fmt" => options.format = true,
+            "--target=rust" => options.target = Target::Rust,
+            "--target=js" | "--target=javascript" => options.target = Target::JavaScript,
+            _ if arg.starts_with("--out=") => {
+                options.output_path = Some(arg["--out=".len()..].to_string());
+            },
+            _ => filename = Some(arg),
+        }
+    }
+
+    let filename = match filename {
+        Some(filename) => filename,
+        None => {
+            if !json_format && !options.show_tokens && !options.show_ast && !options.check_only
+                && !options.dump_tokens && !options.dump_ast && !options.format && options.target == Target::Interpret {
+                run_repl();
+                return;
+            }
+            eprintln!("🐱 MeowLang - Un langage élégant, félin et francophone");
+            eprintln!();
+            eprintln!("Usage: meowlang [--format=json] [--show-tokens] [--show-ast] [--check] [--dump-tokens] [--dump-ast] [--fmt] [--target=rust|js] [--out=fichier] [--lsp] <fichier.miaou>");
+            eprintln!();
+            eprintln!("Exemple:");
+            eprintln!("  meowlang hello.miaou");
+            eprintln!("  meowlang --target=rust --out=hello.rs hello.miaou");
+            process::exit(1);
+        }
+    };
+
+    if let Err(errors) = run_file(filename, &options) {
+        for error in &errors {
+            if json_format {
+                println!("{}", error.to_json());
+            } else {
+                eprintln!("{}", error);
+            }
+        }
         process::exit(1);
     }
 }
@@ -0,0 +1,284 @@
+//! A pluggable registry of native (Rust-implemented) functions callable
+//! from MeowLang source, replacing a hardcoded dispatch match so the
+//! standard library can grow, and embedders can add host functions,
+//! without editing `VM` itself — see `VM::register_native`.
+
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+use rand::Rng;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::ast::Position;
+use crate::error::{ErrorCatalog, MeowLangError};
+use crate::vm::{TaskPool, TaskState, Value};
+
+/// The slice of `VM` state a native function is allowed to touch: enough
+/// to build catalogued errors, to run `aleatoire_normal`'s Box–Muller
+/// cache, and to poll/block on `lancer`'d tasks, without exposing the
+/// frame stack or operand stack. `tasks` is `&mut` (not `&`) since both
+/// `attendre_tache` and `tache_prete` drive a `TaskState` from `Pending`
+/// to `Done`. `pool` lets `attendre`/`attendre_tache` block through
+/// `TaskPool::block_until` instead of a bare sleep/channel `recv`, so
+/// neither call can starve the pool it's part of — see that method's doc
+/// comment.
+pub struct NativeContext<'c> {
+    pub(crate) filename: &'c str,
+    pub(crate) source_lines: &'c [String],
+    pub(crate) normal_cache: &'c mut Option<f64>,
+    pub(crate) tasks: &'c mut [TaskState],
+    pub(crate) pool: &'c TaskPool,
+}
+
+impl<'c> NativeContext<'c> {
+    pub fn error(&self, code: &str, position: &Position) -> MeowLangError {
+        MeowLangError::new(ErrorCatalog::get(code), self.filename.to_string(), position.line, position.column)
+            .with_context(self.source_lines)
+    }
+}
+
+type NativeFn = Rc<dyn Fn(&mut NativeContext, &[Value], &Position) -> Result<Value, MeowLangError>>;
+
+/// Maps builtin names to the closures that implement them. Holds no
+/// reference to `VM` itself — see `NativeContext` — so an embedder's
+/// `register_native` closure can freely capture its own external state.
+pub struct NativeRegistry {
+    functions: HashMap<String, NativeFn>,
+}
+
+impl NativeRegistry {
+    fn new() -> Self {
+        NativeRegistry { functions: HashMap::new() }
+    }
+
+    /// The registry `VM::new` starts every interpreter with: `io`, `math`,
+    /// `string` and `time` self-registering. An embedder building a
+    /// sandboxed host can start from an empty `NativeRegistry::new` and
+    /// register only the groups it trusts instead.
+    pub fn with_stdlib() -> Self {
+        let mut registry = NativeRegistry::new();
+        register_io(&mut registry);
+        register_math(&mut registry);
+        register_string(&mut registry);
+        register_time(&mut registry);
+        registry
+    }
+
+    pub fn register<F>(&mut self, name: &str, func: F)
+    where
+        F: Fn(&mut NativeContext, &[Value], &Position) -> Result<Value, MeowLangError> + 'static,
+    {
+        self.functions.insert(name.to_string(), Rc::new(func));
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<NativeFn> {
+        self.functions.get(name).cloned()
+    }
+}
+
+fn register_io(registry: &mut NativeRegistry) {
+    registry.register("ecrire", |_ctx, args, _position| {
+        let mut output = String::new();
+        for (i, arg) in args.iter().enumerate() {
+            if i > 0 {
+                output.push(' ');
+            }
+            output.push_str(&arg.to_string());
+        }
+        println!("{}", output);
+        Ok(Value::None)
+    });
+
+    registry.register("demander_texte", |_ctx, args, _position| {
+        Ok(match args.first() {
+            Some(arg) => {
+                print!("{} ", arg.to_string());
+                io::stdout().flush().unwrap();
+                let mut input = String::new();
+                io::stdin().read_line(&mut input).unwrap();
+                Value::String(input.trim().to_string())
+            },
+            None => Value::String(String::new()),
+        })
+    });
+
+    registry.register("demander_nombre", |_ctx, args, _position| {
+        Ok(match args.first() {
+            Some(arg) => {
+                print!("{} ", arg.to_string());
+                io::stdout().flush().unwrap();
+                let mut input = String::new();
+                io::stdin().read_line(&mut input).unwrap();
+                Value::Number(input.trim().parse::<f64>().unwrap_or(0.0))
+            },
+            None => Value::Number(0.0),
+        })
+    });
+}
+
+fn register_math(registry: &mut NativeRegistry) {
+    registry.register("aleatoire", |_ctx, args, _position| {
+        Ok(if args.len() >= 2 {
+            let start = args[0].to_number().unwrap_or(0.0) as i64;
+            let end = args[1].to_number().unwrap_or(100.0) as i64;
+            let mut rng = rand::thread_rng();
+            Value::Integer(rng.gen_range(start..=end))
+        } else {
+            Value::Integer(0)
+        })
+    });
+
+    registry.register("aleatoire_normal", |ctx, args, _position| {
+        let mean = args.first().and_then(|v| v.to_number().ok()).unwrap_or(0.0);
+        let std_dev = args.get(1).and_then(|v| v.to_number().ok()).unwrap_or(1.0);
+        let z = if let Some(cached) = ctx.normal_cache.take() {
+            cached
+        } else {
+            let mut rng = rand::thread_rng();
+            let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+            let u2: f64 = rng.gen_range(0.0..1.0);
+            let radius = (-2.0 * u1.ln()).sqrt();
+            let angle = 2.0 * std::f64::consts::PI * u2;
+            *ctx.normal_cache = Some(radius * angle.sin());
+            radius * angle.cos()
+        };
+        Ok(Value::Number(mean + std_dev * z))
+    });
+
+    registry.register("aleatoire_exponentiel", |ctx, args, position| {
+        let lambda = args.first().and_then(|v| v.to_number().ok()).unwrap_or(1.0);
+        if lambda <= 0.0 {
+            return Err(ctx.error("E801", position).with_extra("parametre".to_string(), lambda.to_string()));
+        }
+        let mut rng = rand::thread_rng();
+        let u: f64 = rng.gen_range(f64::EPSILON..1.0);
+        Ok(Value::Number(-u.ln() / lambda))
+    });
+
+    registry.register("aleatoire_flottant", |_ctx, args, _position| {
+        let min = args.first().and_then(|v| v.to_number().ok()).unwrap_or(0.0);
+        let max = args.get(1).and_then(|v| v.to_number().ok()).unwrap_or(1.0);
+        let mut rng = rand::thread_rng();
+        Ok(Value::Number(rng.gen_range(min..max)))
+    });
+
+    registry.register("sqrt", |_ctx, args, _position| {
+        Ok(Value::Number(args.first().and_then(|v| v.to_number().ok()).unwrap_or(0.0).sqrt()))
+    });
+    registry.register("abs", |_ctx, args, _position| {
+        Ok(Value::Number(args.first().and_then(|v| v.to_number().ok()).unwrap_or(0.0).abs()))
+    });
+    registry.register("round", |_ctx, args, _position| {
+        Ok(Value::Integer(args.first().and_then(|v| v.to_number().ok()).unwrap_or(0.0).round() as i64))
+    });
+    registry.register("floor", |_ctx, args, _position| {
+        Ok(Value::Integer(args.first().and_then(|v| v.to_number().ok()).unwrap_or(0.0).floor() as i64))
+    });
+    registry.register("ceil", |_ctx, args, _position| {
+        Ok(Value::Integer(args.first().and_then(|v| v.to_number().ok()).unwrap_or(0.0).ceil() as i64))
+    });
+}
+
+fn register_string(registry: &mut NativeRegistry) {
+    registry.register("minuscule", |_ctx, args, _position| {
+        Ok(match args.first() {
+            Some(arg) => Value::String(arg.to_string().to_lowercase()),
+            None => Value::String(String::new()),
+        })
+    });
+
+    registry.register("majuscule", |_ctx, args, _position| {
+        Ok(match args.first() {
+            Some(arg) => Value::String(arg.to_string().to_uppercase()),
+            None => Value::String(String::new()),
+        })
+    });
+
+    registry.register("longueur", |_ctx, args, _position| {
+        Ok(match args.first() {
+            Some(Value::String(s)) => Value::Integer(s.chars().count() as i64),
+            Some(Value::List(items)) => Value::Integer(items.len() as i64),
+            Some(_) => Value::Integer(0),
+            None => Value::Integer(0),
+        })
+    });
+
+    registry.register("caractere_a", |ctx, args, position| {
+        let chars = string_arg_chars(args.first());
+        let index = args.get(1).and_then(|v| v.to_number().ok()).unwrap_or(-1.0) as i64;
+        if index < 0 || index as usize >= chars.len() {
+            return Err(char_bounds_error(ctx, position, index, chars.len()));
+        }
+        Ok(Value::String(chars[index as usize].to_string()))
+    });
+
+    registry.register("sous_chaine", |ctx, args, position| {
+        let chars = string_arg_chars(args.first());
+        let debut = args.get(1).and_then(|v| v.to_number().ok()).unwrap_or(0.0) as i64;
+        let fin = args.get(2).and_then(|v| v.to_number().ok()).unwrap_or(chars.len() as f64) as i64;
+        if debut < 0 || fin > chars.len() as i64 || debut > fin {
+            return Err(char_bounds_error(ctx, position, if debut < 0 { debut } else { fin }, chars.len()));
+        }
+        Ok(Value::String(chars[debut as usize..fin as usize].iter().collect()))
+    });
+
+    registry.register("inverser", |_ctx, args, _position| {
+        let s = match args.first() {
+            Some(Value::String(s)) => s.clone(),
+            _ => String::new(),
+        };
+        Ok(Value::String(s.chars().rev().collect()))
+    });
+}
+
+fn string_arg_chars(arg: Option<&Value>) -> Vec<char> {
+    match arg {
+        Some(Value::String(s)) => s.chars().collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Builds the catalogued E702 error for a character index/range outside
+/// `0..size`, on Unicode scalar-value boundaries rather than byte offsets.
+fn char_bounds_error(ctx: &NativeContext, position: &Position, index: i64, size: usize) -> MeowLangError {
+    ctx.error("E702", position)
+        .with_extra("index".to_string(), index.to_string())
+        .with_extra("size".to_string(), size.to_string())
+        .with_extra("size_minus_one".to_string(), size.saturating_sub(1).to_string())
+}
+
+fn register_time(registry: &mut NativeRegistry) {
+    // Goes through `TaskPool::block_until` rather than a bare
+    // `thread::sleep`: this call might be running on a pool worker (a
+    // `lancer`'d task that itself sleeps), and a plain sleep would park
+    // that worker's slot — the same hazard `attendre_tache` has, just via
+    // a timer instead of a channel recv. `block_until` keeps this thread
+    // draining other queued tasks while it waits out its own duration.
+    registry.register("attendre", |ctx, args, position| {
+        if let Some(arg) = args.first() {
+            let seconds = arg.to_number().unwrap_or(0.0);
+            if seconds < 0.0 {
+                return Err(ctx.error("E800", position).with_extra("duration".to_string(), seconds.to_string()));
+            }
+            let deadline = Instant::now() + Duration::from_secs_f64(seconds);
+            ctx.pool.block_until(|| if Instant::now() >= deadline { Some(()) } else { None });
+        }
+        Ok(Value::None)
+    });
+
+    registry.register("attendre_tache", |ctx, args, position| {
+        let handle = args.first().and_then(|v| v.to_number().ok()).unwrap_or(-1.0) as i64;
+        if handle < 0 || handle as usize >= ctx.tasks.len() {
+            return Err(ctx.error("E603", position).with_extra("handle".to_string(), handle.to_string()));
+        }
+        ctx.tasks[handle as usize].wait(ctx.pool)
+    });
+
+    registry.register("tache_prete", |ctx, args, position| {
+        let handle = args.first().and_then(|v| v.to_number().ok()).unwrap_or(-1.0) as i64;
+        if handle < 0 || handle as usize >= ctx.tasks.len() {
+            return Err(ctx.error("E603", position).with_extra("handle".to_string(), handle.to_string()));
+        }
+        Ok(Value::Boolean(ctx.tasks[handle as usize].poll()))
+    });
+}
@@ -0,0 +1,347 @@
+//! A constant-folding pass over the parsed `Ast`, run once between
+//! `Parser::parse` and compilation (see `lib::run`). It collapses
+//! `BinaryOp`/`UnaryOp` nodes whose operands are already `Literal`s into a
+//! single `Literal`, so a loop body like `2 + 3 * x` only folds its
+//! constant half once instead of every iteration.
+//!
+//! Folding mirrors `VM`'s own arithmetic (`vm::VM::add_values` and its
+//! siblings) closely enough that running a program before or after this
+//! pass observes the same result types — `+`/`-`/`*` stay `Integer` when
+//! both operands are, division always promotes to `Number` just like the
+//! VM's generic numeric path does. Two exceptions, both in the VM's
+//! favor: a literal `Integer`/`Integer` division that doesn't divide
+//! evenly, and a non-negative integer power with a negative exponent,
+//! would produce a `Value::Rational` at runtime — `LiteralValue` has no
+//! rational variant to fold into, so those two shapes are left
+//! unevaluated rather than approximated. A folded node is never allowed to
+//! change whether the program errors: a literal divisor of zero, or an
+//! operand outside the types the corresponding `VM` helper accepts, is
+//! left unfolded so the usual diagnostic still fires at runtime.
+
+use crate::ast::{ASTNode, InterpolationPart, LiteralValue};
+use crate::interner::Interner;
+use crate::vm::gcd;
+
+/// Folds every compile-time-constant `BinaryOp`/`UnaryOp` in `node`,
+/// descending into every statement body (branches, loops, function
+/// bodies) so constants inside them are folded once here rather than on
+/// every execution. Infallible in practice — the `Result` is kept so a
+/// future check (e.g. a folded literal overflowing a target type) has
+/// somewhere to report without a signature change.
+pub fn optimize(node: ASTNode, interner: &mut Interner) -> Result<ASTNode, crate::error::MeowLangError> {
+    Ok(fold(node, interner))
+}
+
+fn fold(node: ASTNode, interner: &mut Interner) -> ASTNode {
+    match node {
+        ASTNode::Program { statements, span } => ASTNode::Program {
+            statements: fold_block(statements, interner),
+            span,
+        },
+        ASTNode::ExpressionStatement { expression, span } => ASTNode::ExpressionStatement {
+            expression: Box::new(fold(*expression, interner)),
+            span,
+        },
+        ASTNode::Assignment { name, value, span } => ASTNode::Assignment {
+            name,
+            value: Box::new(fold(*value, interner)),
+            span,
+        },
+        ASTNode::IfStatement { condition, then_block, elif_blocks, else_block, span } => ASTNode::IfStatement {
+            condition: Box::new(fold(*condition, interner)),
+            then_block: fold_block(then_block, interner),
+            elif_blocks: elif_blocks
+                .into_iter()
+                .map(|(cond, block)| (fold(cond, interner), fold_block(block, interner)))
+                .collect(),
+            else_block: else_block.map(|block| fold_block(block, interner)),
+            span,
+        },
+        ASTNode::WhileLoop { condition, body, span } => ASTNode::WhileLoop {
+            condition: Box::new(fold(*condition, interner)),
+            body: fold_block(body, interner),
+            span,
+        },
+        ASTNode::RepeatLoop { count, body, span } => ASTNode::RepeatLoop {
+            count: Box::new(fold(*count, interner)),
+            body: fold_block(body, interner),
+            span,
+        },
+        ASTNode::ForEachLoop { iterator, iterable, body, span } => ASTNode::ForEachLoop {
+            iterator,
+            iterable: Box::new(fold(*iterable, interner)),
+            body: fold_block(body, interner),
+            span,
+        },
+        ASTNode::FunctionDef { name, parameters, body, span } => ASTNode::FunctionDef {
+            name,
+            parameters,
+            body: fold_block(body, interner),
+            span,
+        },
+        ASTNode::ReturnStatement { value, span } => ASTNode::ReturnStatement {
+            value: value.map(|v| Box::new(fold(*v, interner))),
+            span,
+        },
+        ASTNode::TryExcept { try_block, handlers, span } => ASTNode::TryExcept {
+            try_block: fold_block(try_block, interner),
+            handlers: handlers
+                .into_iter()
+                .map(|handler| crate::ast::ExceptHandler {
+                    body: fold_block(handler.body, interner),
+                    ..handler
+                })
+                .collect(),
+            span,
+        },
+        ASTNode::ListNode { elements, span, leading_trivia } => ASTNode::ListNode {
+            elements: elements.into_iter().map(|e| fold(e, interner)).collect(),
+            span,
+            leading_trivia,
+        },
+        ASTNode::DictNode { pairs, span } => ASTNode::DictNode {
+            pairs: pairs
+                .into_iter()
+                .map(|(key, value)| (fold(key, interner), fold(value, interner)))
+                .collect(),
+            span,
+        },
+        ASTNode::IndexAccess { object, index, span } => ASTNode::IndexAccess {
+            object: Box::new(fold(*object, interner)),
+            index: Box::new(fold(*index, interner)),
+            span,
+        },
+        ASTNode::IndexAssignment { object, index, value, span } => ASTNode::IndexAssignment {
+            object: Box::new(fold(*object, interner)),
+            index: Box::new(fold(*index, interner)),
+            value: Box::new(fold(*value, interner)),
+            span,
+        },
+        ASTNode::FunctionCall { name, arguments, span, leading_trivia } => ASTNode::FunctionCall {
+            name,
+            arguments: arguments.into_iter().map(|a| fold(a, interner)).collect(),
+            span,
+            leading_trivia,
+        },
+        ASTNode::BinaryOp { left, operator, right, span } => {
+            let left = fold(*left, interner);
+            let right = fold(*right, interner);
+            if let (ASTNode::Literal { value: lv, .. }, ASTNode::Literal { value: rv, .. }) = (&left, &right) {
+                if let Some(folded) = fold_binary_literals(lv, &operator, rv, interner) {
+                    return ASTNode::Literal { value: folded, span };
+                }
+            }
+            ASTNode::BinaryOp { left: Box::new(left), operator, right: Box::new(right), span }
+        },
+        ASTNode::UnaryOp { operator, operand, span } => {
+            let operand = fold(*operand, interner);
+            if let ASTNode::Literal { value, .. } = &operand {
+                if let Some(folded) = fold_unary_literal(&operator, value, interner) {
+                    return ASTNode::Literal { value: folded, span };
+                }
+            }
+            ASTNode::UnaryOp { operator, operand: Box::new(operand), span }
+        },
+        ASTNode::Interpolation { parts, span } => ASTNode::Interpolation {
+            parts: parts
+                .into_iter()
+                .map(|part| match part {
+                    InterpolationPart::Literal(s) => InterpolationPart::Literal(s),
+                    InterpolationPart::Expr(expr) => InterpolationPart::Expr(Box::new(fold(*expr, interner))),
+                })
+                .collect(),
+            span,
+        },
+        // Literals, identifiers, and the parameterless statements have
+        // nothing to fold or descend into.
+        other => other,
+    }
+}
+
+fn fold_block(statements: Vec<ASTNode>, interner: &mut Interner) -> Vec<ASTNode> {
+    statements.into_iter().map(|s| fold(s, interner)).collect()
+}
+
+/// `Some(f64)` for the two numeric `LiteralValue`s, `None` for anything
+/// else — mirrors the set of `Value` variants `VM::as_number` accepts
+/// without the `String`-parsing case, which this pass deliberately never
+/// folds (a string literal that fails to parse as a number needs to raise
+/// its usual runtime error, not vanish at fold time).
+fn literal_as_f64(value: &LiteralValue) -> Option<f64> {
+    match value {
+        LiteralValue::Integer(i) => Some(*i as f64),
+        LiteralValue::Number(n) => Some(*n),
+        _ => None,
+    }
+}
+
+/// The exact text `Value::to_string()` would produce for the matching
+/// runtime value — used to fold `+`'s string-concatenation behavior,
+/// which coerces whichever side isn't a string through its own
+/// `to_string()`.
+fn literal_display(value: &LiteralValue, interner: &Interner) -> String {
+    match value {
+        LiteralValue::String(sym) => interner.resolve(*sym).to_string(),
+        LiteralValue::Number(n) => n.to_string(),
+        LiteralValue::Integer(i) => i.to_string(),
+        LiteralValue::Char(c) => c.to_string(),
+        LiteralValue::Boolean(b) => if *b { "vrai" } else { "faux" }.to_string(),
+        LiteralValue::None => String::new(),
+    }
+}
+
+/// Mirrors `Value::to_bool()` — infallible for every `LiteralValue`
+/// variant, so `et`/`ou`/`non` always fold when their operands do.
+fn literal_truthy(value: &LiteralValue, interner: &Interner) -> bool {
+    match value {
+        LiteralValue::String(sym) => !interner.resolve(*sym).is_empty(),
+        LiteralValue::Number(n) => *n != 0.0,
+        LiteralValue::Integer(i) => *i != 0,
+        LiteralValue::Char(_) => true,
+        LiteralValue::Boolean(b) => *b,
+        LiteralValue::None => false,
+    }
+}
+
+/// Mirrors `Value::values_equal()`: equal only when both literals are the
+/// same kind (an `Integer` literal is never equal to a `Number` literal,
+/// matching the VM's own fallthrough to `false`).
+fn literal_eq(l: &LiteralValue, r: &LiteralValue, interner: &Interner) -> bool {
+    match (l, r) {
+        (LiteralValue::String(a), LiteralValue::String(b)) => interner.resolve(*a) == interner.resolve(*b),
+        (LiteralValue::Integer(a), LiteralValue::Integer(b)) => a == b,
+        (LiteralValue::Number(a), LiteralValue::Number(b)) => (a - b).abs() < f64::EPSILON,
+        (LiteralValue::Char(a), LiteralValue::Char(b)) => a == b,
+        (LiteralValue::Boolean(a), LiteralValue::Boolean(b)) => a == b,
+        (LiteralValue::None, LiteralValue::None) => true,
+        _ => false,
+    }
+}
+
+fn fold_add(l: &LiteralValue, r: &LiteralValue, interner: &mut Interner) -> Option<LiteralValue> {
+    if matches!(l, LiteralValue::String(_) | LiteralValue::Char(_)) || matches!(r, LiteralValue::String(_) | LiteralValue::Char(_)) {
+        let text = format!("{}{}", literal_display(l, interner), literal_display(r, interner));
+        return Some(LiteralValue::String(interner.intern(&text)));
+    }
+    match (l, r) {
+        (LiteralValue::Integer(a), LiteralValue::Integer(b)) => a.checked_add(*b).map(LiteralValue::Integer),
+        _ => Some(LiteralValue::Number(literal_as_f64(l)? + literal_as_f64(r)?)),
+    }
+}
+
+fn fold_sub(l: &LiteralValue, r: &LiteralValue) -> Option<LiteralValue> {
+    match (l, r) {
+        (LiteralValue::Integer(a), LiteralValue::Integer(b)) => a.checked_sub(*b).map(LiteralValue::Integer),
+        _ => Some(LiteralValue::Number(literal_as_f64(l)? - literal_as_f64(r)?)),
+    }
+}
+
+fn fold_mul(l: &LiteralValue, r: &LiteralValue) -> Option<LiteralValue> {
+    match (l, r) {
+        (LiteralValue::Integer(a), LiteralValue::Integer(b)) => a.checked_mul(*b).map(LiteralValue::Integer),
+        _ => Some(LiteralValue::Number(literal_as_f64(l)? * literal_as_f64(r)?)),
+    }
+}
+
+/// Shared by `/` and `//` — both compile to the same `Instr::Div` (see
+/// `compiler.rs`), so there's no distinct floor-division behavior to
+/// preserve here.
+fn fold_div(l: &LiteralValue, r: &LiteralValue) -> Option<LiteralValue> {
+    if let (LiteralValue::Integer(a), LiteralValue::Integer(b)) = (l, r) {
+        if *b == 0 {
+            return None;
+        }
+        // Mirror `vm::VM::div_values`'s `make_rational` exactly: reduce by
+        // the gcd, and only fold when that reduces to a whole number — an
+        // Integer/Integer division that doesn't divide evenly produces a
+        // `Value::Rational` at runtime, which `LiteralValue` has no variant
+        // for, so it's left unfolded (see this module's doc comment).
+        let g = gcd(*a, *b);
+        let (mut num, mut den) = (a / g, b / g);
+        if den < 0 {
+            num = -num;
+            den = -den;
+        }
+        return if den == 1 { Some(LiteralValue::Integer(num)) } else { None };
+    }
+
+    let a = literal_as_f64(l)?;
+    let b = literal_as_f64(r)?;
+    if b == 0.0 {
+        return None;
+    }
+    Some(LiteralValue::Number(a / b))
+}
+
+fn fold_mod(l: &LiteralValue, r: &LiteralValue) -> Option<LiteralValue> {
+    let a = literal_as_f64(l)?;
+    let b = literal_as_f64(r)?;
+    if b == 0.0 {
+        return None;
+    }
+    Some(LiteralValue::Number(a % b))
+}
+
+fn fold_pow(l: &LiteralValue, r: &LiteralValue) -> Option<LiteralValue> {
+    if let (LiteralValue::Integer(base), LiteralValue::Integer(exp)) = (l, r) {
+        if *exp >= 0 {
+            if let Ok(e) = u32::try_from(*exp) {
+                if let Some(result) = base.checked_pow(e) {
+                    return Some(LiteralValue::Integer(result));
+                }
+            }
+        }
+        // Negative exponent, or an overflowing positive one: the VM would
+        // produce an exact `Rational` (or raise E500 for a zero base),
+        // neither of which `LiteralValue` can represent — leave it.
+        return None;
+    }
+    Some(LiteralValue::Number(literal_as_f64(l)?.powf(literal_as_f64(r)?)))
+}
+
+fn fold_cmp(op: &str, l: &LiteralValue, r: &LiteralValue) -> Option<LiteralValue> {
+    let a = literal_as_f64(l)?;
+    let b = literal_as_f64(r)?;
+    let result = match op {
+        "<" => a < b,
+        ">" => a > b,
+        "<=" => a <= b,
+        ">=" => a >= b,
+        _ => unreachable!("caller only dispatches the four ordering operators here"),
+    };
+    Some(LiteralValue::Boolean(result))
+}
+
+fn fold_binary_literals(
+    l: &LiteralValue,
+    operator: &str,
+    r: &LiteralValue,
+    interner: &mut Interner,
+) -> Option<LiteralValue> {
+    match operator {
+        "+" => fold_add(l, r, interner),
+        "-" => fold_sub(l, r),
+        "*" => fold_mul(l, r),
+        "/" | "//" => fold_div(l, r),
+        "%" => fold_mod(l, r),
+        "**" => fold_pow(l, r),
+        "=" => Some(LiteralValue::Boolean(literal_eq(l, r, interner))),
+        "!=" => Some(LiteralValue::Boolean(!literal_eq(l, r, interner))),
+        "<" | ">" | "<=" | ">=" => fold_cmp(operator, l, r),
+        "et" => Some(LiteralValue::Boolean(literal_truthy(l, interner) && literal_truthy(r, interner))),
+        "ou" => Some(LiteralValue::Boolean(literal_truthy(l, interner) || literal_truthy(r, interner))),
+        // Pipe operators (`|>`, `|:`, `|?`, `|&`) aren't arithmetic — they
+        // never fold even when both sides happen to be literals.
+        _ => None,
+    }
+}
+
+fn fold_unary_literal(operator: &str, value: &LiteralValue, interner: &Interner) -> Option<LiteralValue> {
+    match operator {
+        // Matches `Instr::Neg`, which always produces a `Value::Number`
+        // regardless of the operand's type.
+        "-" => literal_as_f64(value).map(|n| LiteralValue::Number(-n)),
+        "non" => Some(LiteralValue::Boolean(!literal_truthy(value, interner))),
+        _ => None,
+    }
+}
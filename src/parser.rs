@@ -1,24 +1,83 @@
-use crate::ast::{ASTNode, LiteralValue, Position};
-use crate::token::{Token, TokenType, TokenValue};
-use crate::error::{ErrorCatalog, MeowLangError};
+use crate::ast::{ASTNode, ExceptHandler, InterpolationPart, LiteralValue, Span};
+use crate::token::{InterpolationSegment, Token, TokenType, TokenValue};
+use crate::error::{DiagnosticSink, ErrorCatalog, MeowLangError};
+use crate::interner::Interner;
+use crate::builtins::{self, Arity};
+use crate::lexer::Lexer;
 
-pub struct Parser {
+pub struct Parser<'a> {
     tokens: Vec<Token>,
     pos: usize,
     filename: String,
     source_lines: Vec<String>,
+    sink: DiagnosticSink,
+    /// Set once the sink is full; every statement loop bails out as soon
+    /// as this is set instead of trying to keep recovering forever.
+    halted: bool,
+    interner: &'a mut Interner,
 }
 
-impl Parser {
-    pub fn new(tokens: Vec<Token>, filename: String, source_lines: Vec<String>) -> Self {
+impl<'a> Parser<'a> {
+    pub fn new(tokens: Vec<Token>, filename: String, source_lines: Vec<String>, interner: &'a mut Interner) -> Self {
         Parser {
             tokens,
             pos: 0,
             filename,
             source_lines,
+            sink: DiagnosticSink::default(),
+            halted: false,
+            interner,
         }
     }
-    
+
+    /// Records a parse error in the sink and, if the sink is now full,
+    /// marks the parser as halted so the enclosing statement loops stop.
+    fn record_error(&mut self, error: MeowLangError) {
+        if self.halted {
+            return;
+        }
+        if self.sink.push(error) {
+            self.halted = true;
+        }
+    }
+
+    /// Panic-mode recovery: skip tokens until a synchronizing point — a
+    /// `Newline`, a `Dedent`, `Eof`/`meow`, or the start of a new
+    /// statement (`ecrire`, `si`, `repeter`, `tant que`, `pour chaque`,
+    /// `fonction`, `retour`, `casser`, `continuer`, `essayer`) — then let
+    /// the caller resume parsing from there. Every arm other than the two
+    /// early returns falls into `_ => self.advance()`, so a call always
+    /// consumes at least one token and termination is guaranteed.
+    ///
+    /// `RParen`/`Comma` are never sync points here on purpose: this is
+    /// only ever invoked from the statement-level loops (`parse()`,
+    /// `parse_block()`) after an entire statement has failed, and a
+    /// failure inside a call's argument list already unwound out past the
+    /// call via `?` before `synchronize` runs — by then there is no open
+    /// bracket left to desync, so no bracket-depth counter is needed.
+    fn synchronize(&mut self) {
+        loop {
+            match self.current().token_type {
+                TokenType::Eof | TokenType::Meow => return,
+                TokenType::Newline | TokenType::Dedent => {
+                    self.advance();
+                    return;
+                }
+                TokenType::Si
+                | TokenType::Repeter
+                | TokenType::TantQue
+                | TokenType::PourChaque
+                | TokenType::Fonction
+                | TokenType::Retour
+                | TokenType::Casser
+                | TokenType::Continuer
+                | TokenType::Essayer
+                | TokenType::Ecrire => return,
+                _ => self.advance(),
+            }
+        }
+    }
+
     fn current(&self) -> &Token {
         if self.pos < self.tokens.len() {
             &self.tokens[self.pos]
@@ -26,17 +85,17 @@ impl Parser {
             self.tokens.last().unwrap()
         }
     }
-    
+
     fn peek(&self, offset: usize) -> Option<&Token> {
         self.tokens.get(self.pos + offset)
     }
-    
+
     fn advance(&mut self) {
         if self.pos < self.tokens.len() {
             self.pos += 1;
         }
     }
-    
+
     fn expect(&mut self, token_type: TokenType) -> Result<Token, MeowLangError> {
         if self.current().token_type == token_type {
             let token = self.current().clone();
@@ -51,58 +110,114 @@ impl Parser {
             ).with_context(&self.source_lines))
         }
     }
-    
+
     fn skip_newlines(&mut self) {
         while self.current().token_type == TokenType::Newline {
             self.advance();
         }
     }
-    
-    fn position_from_token(&self, token: &Token) -> Position {
-        Position::new(token.line, token.column)
+
+    fn span_from_token(&self, token: &Token) -> Span {
+        token.span.clone()
+    }
+
+    /// Parses one `{expr}` segment of an interpolated string. `source` is
+    /// the raw text the lexer captured between the braces; it's re-lexed
+    /// and re-parsed with a fresh `Lexer`/`Parser` pair sharing this
+    /// parser's `Interner`, then `parse_expression` builds it exactly like
+    /// any other expression — interpolation isn't a separate grammar.
+    ///
+    /// Lexing/parsing the embedded source happens against its own
+    /// one-line buffer, so a failure inside it is reported at the
+    /// position of the enclosing string literal (`token`) rather than at
+    /// an offset into that buffer.
+    fn parse_interpolated_expr(&mut self, source: &str, token: &Token) -> Result<ASTNode, MeowLangError> {
+        let embedded_error = || MeowLangError::new(
+            ErrorCatalog::get("E110"),
+            self.filename.clone(),
+            token.line,
+            token.column,
+        ).with_context(&self.source_lines);
+
+        let mut lexer = Lexer::new(source.trim().to_string(), self.filename.clone(), &mut *self.interner);
+        let tokens = lexer.tokenize().map_err(|_| embedded_error())?;
+
+        let mut sub_parser = Parser::new(tokens, self.filename.clone(), self.source_lines.clone(), &mut *self.interner);
+        sub_parser.parse_expression().map_err(|_| embedded_error())
     }
-    
-    pub fn parse(&mut self) -> Result<ASTNode, MeowLangError> {
+
+    pub fn parse(&mut self) -> Result<ASTNode, Vec<MeowLangError>> {
         self.skip_newlines();
-        
+
         if self.current().token_type != TokenType::Miaou {
-            return Err(MeowLangError::new(
+            return Err(vec![MeowLangError::new(
                 ErrorCatalog::get("E000"),
                 self.filename.clone(),
                 1,
                 1,
-            ).with_context(&self.source_lines));
+            ).with_context(&self.source_lines)]);
         }
-        
-        let start_pos = self.position_from_token(self.current());
+
+        let start_span = self.span_from_token(self.current());
         self.advance();
         self.skip_newlines();
-        
+
+        // A file-level body is indented under `miaou` like any other
+        // block and the lexer emits a matching `Indent`/`Dedent` pair for
+        // it, but `lib::run_repl` synthesizes `miaou\n<line>\nmeow` around
+        // a single unindented REPL line with no such pair — so unlike
+        // `parse_block`'s callers, the `Indent` here is optional, and only
+        // consumed (along with its `Dedent`) when the lexer actually sent one.
+        let has_indent = self.current().token_type == TokenType::Indent;
+        if has_indent {
+            self.advance();
+        }
+
         let mut statements = Vec::new();
-        
-        while self.current().token_type != TokenType::Meow && self.current().token_type != TokenType::Eof {
-            statements.push(self.parse_statement()?);
+
+        while !self.halted && self.current().token_type != TokenType::Meow
+            && self.current().token_type != TokenType::Dedent
+            && self.current().token_type != TokenType::Eof
+        {
+            match self.parse_statement() {
+                Ok(stmt) => statements.push(stmt),
+                Err(error) => {
+                    self.record_error(error);
+                    self.synchronize();
+                }
+            }
             self.skip_newlines();
         }
-        
-        if self.current().token_type != TokenType::Meow {
-            return Err(MeowLangError::new(
+
+        if has_indent && self.current().token_type == TokenType::Dedent {
+            self.advance();
+        }
+        self.skip_newlines();
+
+        if !self.halted && self.current().token_type != TokenType::Meow {
+            self.record_error(MeowLangError::new(
                 ErrorCatalog::get("E001"),
                 self.filename.clone(),
                 self.current().line,
                 self.current().column,
             ).with_context(&self.source_lines));
         }
-        
+
+        if !self.sink.errors.is_empty() {
+            return Err(std::mem::take(&mut self.sink.errors));
+        }
+
+        let span = start_span.merge(&self.current().span);
+
         Ok(ASTNode::Program {
             statements,
-            position: start_pos,
+            span,
         })
     }
-    
+
     fn parse_statement(&mut self) -> Result<ASTNode, MeowLangError> {
         self.skip_newlines();
-        
+
         match self.current().token_type {
             TokenType::Ecrire => self.parse_ecrire(),
             TokenType::Si => self.parse_if(),
@@ -111,23 +226,34 @@ impl Parser {
             TokenType::PourChaque => self.parse_foreach(),
             TokenType::Fonction => self.parse_function_def(),
             TokenType::Retour => self.parse_return(),
+            TokenType::Casser => self.parse_break(),
+            TokenType::Continuer => self.parse_continue(),
             TokenType::Essayer => self.parse_try_except(),
             TokenType::Identifier => {
-                if self.peek(1).map(|t| &t.token_type) == Some(&TokenType::Assign) {
+                if matches!(
+                    self.peek(1).map(|t| &t.token_type),
+                    Some(&TokenType::Assign)
+                        | Some(&TokenType::PlusAssign)
+                        | Some(&TokenType::MinusAssign)
+                        | Some(&TokenType::MultiplyAssign)
+                        | Some(&TokenType::DivideAssign)
+                ) {
                     self.parse_assignment()
+                } else if self.peek(1).map(|t| &t.token_type) == Some(&TokenType::LBracket) {
+                    self.parse_index_assignment()
                 } else if self.peek(1).map(|t| &t.token_type) == Some(&TokenType::LParen) {
                     let expr = self.parse_expression()?;
                     self.skip_newlines();
                     Ok(ASTNode::ExpressionStatement {
-                        expression: Box::new(expr.clone()),
-                        position: expr.position().clone(),
+                        span: expr.span().clone(),
+                        expression: Box::new(expr),
                     })
                 } else {
                     let expr = self.parse_expression()?;
                     self.skip_newlines();
                     Ok(ASTNode::ExpressionStatement {
-                        expression: Box::new(expr.clone()),
-                        position: expr.position().clone(),
+                        span: expr.span().clone(),
+                        expression: Box::new(expr),
                     })
                 }
             },
@@ -135,94 +261,191 @@ impl Parser {
                 let expr = self.parse_expression()?;
                 self.skip_newlines();
                 Ok(ASTNode::ExpressionStatement {
-                    expression: Box::new(expr.clone()),
-                    position: expr.position().clone(),
+                    span: expr.span().clone(),
+                    expression: Box::new(expr),
                 })
             }
         }
     }
-    
+
     fn parse_ecrire(&mut self) -> Result<ASTNode, MeowLangError> {
         let token = self.current().clone();
-        let position = self.position_from_token(&token);
+        let mut span = self.span_from_token(&token);
         self.advance();
-        
+
         let mut args = Vec::new();
-        
+
         loop {
             self.skip_newlines();
-            if self.current().token_type == TokenType::Newline || 
+            if self.current().token_type == TokenType::Newline ||
                self.current().token_type == TokenType::Eof {
                 break;
             }
-            
-            args.push(self.parse_expression()?);
-            
+
+            let arg = self.parse_expression()?;
+            span = span.merge(arg.span());
+            args.push(arg);
+
             if self.current().token_type != TokenType::Newline &&
                self.current().token_type != TokenType::Eof &&
                self.current().token_type != TokenType::Comma {
                 continue;
             }
-            
+
             if self.current().token_type == TokenType::Comma {
                 self.advance();
             } else {
                 break;
             }
         }
-        
+
         Ok(ASTNode::FunctionCall {
-            name: "ecrire".to_string(),
+            name: self.interner.intern("ecrire"),
             arguments: args,
-            position,
+            span,
+            leading_trivia: Vec::new(),
         })
     }
-    
+
     fn parse_assignment(&mut self) -> Result<ASTNode, MeowLangError> {
         let name_token = self.current().clone();
-        let position = self.position_from_token(&name_token);
-        
+        let span = self.span_from_token(&name_token);
+
         let name = if let TokenValue::String(s) = &name_token.value {
-            s.clone()
+            *s
         } else {
             return Err(MeowLangError::new(
-                ErrorCatalog::get("E104"),
+                ErrorCatalog::get("E108"),
                 self.filename.clone(),
                 name_token.line,
                 name_token.column,
             ).with_context(&self.source_lines));
         };
-        
+
         self.advance();
-        self.expect(TokenType::Assign)?;
-        
-        let value = self.parse_expression()?;
-        
+
+        let compound_operator = match self.current().token_type {
+            TokenType::PlusAssign => Some("+"),
+            TokenType::MinusAssign => Some("-"),
+            TokenType::MultiplyAssign => Some("*"),
+            TokenType::DivideAssign => Some("/"),
+            _ => None,
+        };
+
+        if compound_operator.is_some() {
+            self.advance();
+        } else {
+            self.expect(TokenType::Assign)?;
+        }
+
+        let rhs = self.parse_expression()?;
+        let span = span.merge(rhs.span());
+
+        let value = match compound_operator {
+            Some(operator) => Box::new(ASTNode::BinaryOp {
+                left: Box::new(ASTNode::Identifier { name, span: span.clone() }),
+                operator: operator.to_string(),
+                right: Box::new(rhs),
+                span: span.clone(),
+            }),
+            None => Box::new(rhs),
+        };
+
         Ok(ASTNode::Assignment {
             name,
-            value: Box::new(value),
-            position,
+            value,
+            span,
         })
     }
-    
+
+    /// Parses `objet[clef] = valeur`, `objet[clef] += valeur` and friends,
+    /// and plain `objet[clef]` read as a statement if no assignment
+    /// operator follows (mirroring `ecrire liste[0]` being valid via the
+    /// generic expression-statement fallback). The target is parsed via
+    /// `parse_postfix` rather than `parse_expression` — `parse_expression`
+    /// descends into `parse_comparison`, which treats a bare `=` as the
+    /// equality operator and would swallow `=`/the right-hand side into a
+    /// `BinaryOp` before this function ever saw the `=` to check for.
+    fn parse_index_assignment(&mut self) -> Result<ASTNode, MeowLangError> {
+        let target = self.parse_postfix()?;
+
+        let compound_operator = match self.current().token_type {
+            TokenType::PlusAssign => Some("+"),
+            TokenType::MinusAssign => Some("-"),
+            TokenType::MultiplyAssign => Some("*"),
+            TokenType::DivideAssign => Some("/"),
+            _ => None,
+        };
+
+        if compound_operator.is_none() && self.current().token_type != TokenType::Assign {
+            self.skip_newlines();
+            return Ok(ASTNode::ExpressionStatement {
+                span: target.span().clone(),
+                expression: Box::new(target),
+            });
+        }
+
+        let (object, index) = match target {
+            ASTNode::IndexAccess { object, index, .. } => (object, index),
+            _ => return Err(MeowLangError::new(
+                ErrorCatalog::get("E104"),
+                self.filename.clone(),
+                self.current().line,
+                self.current().column,
+            ).with_context(&self.source_lines)),
+        };
+
+        self.advance();
+        let rhs = self.parse_expression()?;
+        let span = object.span().merge(rhs.span());
+
+        // Mirrors `parse_assignment`'s compound-op handling for plain
+        // identifiers: `objet[clef] += valeur` desugars to
+        // `objet[clef] = objet[clef] + valeur`.
+        let value = match compound_operator {
+            Some(operator) => Box::new(ASTNode::BinaryOp {
+                left: Box::new(ASTNode::IndexAccess {
+                    object: object.clone(),
+                    index: index.clone(),
+                    span: span.clone(),
+                }),
+                operator: operator.to_string(),
+                right: Box::new(rhs),
+                span: span.clone(),
+            }),
+            None => Box::new(rhs),
+        };
+
+        Ok(ASTNode::IndexAssignment {
+            object,
+            index,
+            value,
+            span,
+        })
+    }
+
     fn parse_if(&mut self) -> Result<ASTNode, MeowLangError> {
-        let position = self.position_from_token(self.current());
+        let start_span = self.span_from_token(self.current());
         self.advance();
-        
+
         let condition = self.parse_expression()?;
-        
+
         self.expect(TokenType::Alors)?;
         self.expect(TokenType::Colon)?;
         self.skip_newlines();
         self.expect(TokenType::Indent)?;
-        
-        let then_block = self.parse_block()?;
-        
+
+        let then_block = self.parse_block();
+
         let mut elif_blocks = Vec::new();
         let mut else_block = None;
-        
+        let mut span = start_span;
+        if let Some(last) = then_block.last() {
+            span = span.merge(last.span());
+        }
+
         self.skip_newlines();
-        
+
         while self.current().token_type == TokenType::SinonSi {
             self.advance();
             let elif_condition = self.parse_expression()?;
@@ -230,269 +453,464 @@ impl Parser {
             self.expect(TokenType::Colon)?;
             self.skip_newlines();
             self.expect(TokenType::Indent)?;
-            let elif_body = self.parse_block()?;
+            let elif_body = self.parse_block();
+            if let Some(last) = elif_body.last() {
+                span = span.merge(last.span());
+            }
             elif_blocks.push((elif_condition, elif_body));
             self.skip_newlines();
         }
-        
+
         if self.current().token_type == TokenType::Sinon {
             self.advance();
             self.expect(TokenType::Colon)?;
             self.skip_newlines();
             self.expect(TokenType::Indent)?;
-            else_block = Some(self.parse_block()?);
+            let body = self.parse_block();
+            if let Some(last) = body.last() {
+                span = span.merge(last.span());
+            }
+            else_block = Some(body);
         }
-        
+
         Ok(ASTNode::IfStatement {
             condition: Box::new(condition),
             then_block,
             elif_blocks,
             else_block,
-            position,
+            span,
         })
     }
-    
+
     fn parse_while(&mut self) -> Result<ASTNode, MeowLangError> {
-        let position = self.position_from_token(self.current());
+        let start_span = self.span_from_token(self.current());
         self.advance();
-        
+
         let condition = self.parse_expression()?;
-        
+
         self.expect(TokenType::Colon)?;
         self.skip_newlines();
         self.expect(TokenType::Indent)?;
-        
-        let body = self.parse_block()?;
-        
+
+        let body = self.parse_block();
+        let span = match body.last() {
+            Some(last) => start_span.merge(last.span()),
+            None => start_span,
+        };
+
         Ok(ASTNode::WhileLoop {
             condition: Box::new(condition),
             body,
-            position,
+            span,
         })
     }
-    
+
     fn parse_repeat(&mut self) -> Result<ASTNode, MeowLangError> {
-        let position = self.position_from_token(self.current());
+        let start_span = self.span_from_token(self.current());
         self.advance();
-        
+
         let count = self.parse_expression()?;
-        
+
         self.expect(TokenType::Fois)?;
         self.expect(TokenType::Colon)?;
         self.skip_newlines();
         self.expect(TokenType::Indent)?;
-        
-        let body = self.parse_block()?;
-        
+
+        let body = self.parse_block();
+        let span = match body.last() {
+            Some(last) => start_span.merge(last.span()),
+            None => start_span,
+        };
+
         Ok(ASTNode::RepeatLoop {
             count: Box::new(count),
             body,
-            position,
+            span,
         })
     }
-    
+
     fn parse_foreach(&mut self) -> Result<ASTNode, MeowLangError> {
-        let position = self.position_from_token(self.current());
+        let start_span = self.span_from_token(self.current());
         self.advance();
-        
+
+        if self.current().token_type != TokenType::Identifier {
+            return Err(MeowLangError::new(
+                ErrorCatalog::get("E108"),
+                self.filename.clone(),
+                self.current().line,
+                self.current().column,
+            ).with_context(&self.source_lines));
+        }
         let iterator_token = self.expect(TokenType::Identifier)?;
         let iterator = if let TokenValue::String(s) = iterator_token.value {
             s
         } else {
             return Err(MeowLangError::new(
-                ErrorCatalog::get("E104"),
+                ErrorCatalog::get("E108"),
                 self.filename.clone(),
                 iterator_token.line,
                 iterator_token.column,
             ).with_context(&self.source_lines));
         };
-        
+
         self.expect(TokenType::Dans)?;
-        
+
         let iterable = self.parse_expression()?;
-        
+
         self.expect(TokenType::Colon)?;
         self.skip_newlines();
         self.expect(TokenType::Indent)?;
-        
-        let body = self.parse_block()?;
-        
+
+        let body = self.parse_block();
+        let span = match body.last() {
+            Some(last) => start_span.merge(last.span()),
+            None => start_span,
+        };
+
         Ok(ASTNode::ForEachLoop {
             iterator,
             iterable: Box::new(iterable),
             body,
-            position,
+            span,
         })
     }
-    
+
     fn parse_function_def(&mut self) -> Result<ASTNode, MeowLangError> {
-        let position = self.position_from_token(self.current());
+        let start_span = self.span_from_token(self.current());
         self.advance();
-        
+
+        if self.current().token_type != TokenType::Identifier {
+            return Err(MeowLangError::new(
+                ErrorCatalog::get("E106"),
+                self.filename.clone(),
+                self.current().line,
+                self.current().column,
+            ).with_context(&self.source_lines));
+        }
         let name_token = self.expect(TokenType::Identifier)?;
         let name = if let TokenValue::String(s) = name_token.value {
             s
         } else {
             return Err(MeowLangError::new(
-                ErrorCatalog::get("E104"),
+                ErrorCatalog::get("E106"),
                 self.filename.clone(),
                 name_token.line,
                 name_token.column,
             ).with_context(&self.source_lines));
         };
-        
+
+        if self.current().token_type != TokenType::LParen {
+            return Err(MeowLangError::new(
+                ErrorCatalog::get("E107"),
+                self.filename.clone(),
+                self.current().line,
+                self.current().column,
+            ).with_context(&self.source_lines));
+        }
         self.expect(TokenType::LParen)?;
-        
+
         let mut parameters = Vec::new();
-        
+
         while self.current().token_type != TokenType::RParen {
             let param_token = self.expect(TokenType::Identifier)?;
             if let TokenValue::String(s) = param_token.value {
                 parameters.push(s);
             }
-            
+
             if self.current().token_type == TokenType::Comma {
                 self.advance();
             }
         }
-        
+
         self.expect(TokenType::RParen)?;
         self.expect(TokenType::Colon)?;
         self.skip_newlines();
         self.expect(TokenType::Indent)?;
-        
-        let body = self.parse_block()?;
-        
+
+        let mut body = self.parse_block();
+        let span = match body.last() {
+            Some(last) => start_span.merge(last.span()),
+            None => start_span,
+        };
+
+        // An `ExpressionStatement` with nothing after it is the function's
+        // value, like the last expression of a block in Rhai — rewrite it
+        // into a `ReturnStatement` so `Compiler`/`VM` don't need to know
+        // about this convention at all. An explicit `retour` is already a
+        // `ReturnStatement`, so it's untouched either way.
+        if let Some(ASTNode::ExpressionStatement { .. }) = body.last() {
+            if let Some(ASTNode::ExpressionStatement { expression, span }) = body.pop() {
+                body.push(ASTNode::ReturnStatement {
+                    value: Some(expression),
+                    span,
+                });
+            }
+        }
+
         Ok(ASTNode::FunctionDef {
             name,
             parameters,
             body,
-            position,
+            span,
         })
     }
-    
+
     fn parse_return(&mut self) -> Result<ASTNode, MeowLangError> {
-        let position = self.position_from_token(self.current());
+        let span = self.span_from_token(self.current());
         self.advance();
-        
-        if self.current().token_type == TokenType::Newline || 
+
+        if self.current().token_type == TokenType::Newline ||
            self.current().token_type == TokenType::Eof {
             return Ok(ASTNode::ReturnStatement {
                 value: None,
-                position,
+                span,
             });
         }
-        
+
         let value = self.parse_expression()?;
-        
+        let span = span.merge(value.span());
+
         Ok(ASTNode::ReturnStatement {
             value: Some(Box::new(value)),
-            position,
+            span,
         })
     }
-    
+
+    fn parse_break(&mut self) -> Result<ASTNode, MeowLangError> {
+        let span = self.span_from_token(self.current());
+        self.advance();
+        Ok(ASTNode::BreakStatement { span })
+    }
+
+    fn parse_continue(&mut self) -> Result<ASTNode, MeowLangError> {
+        let span = self.span_from_token(self.current());
+        self.advance();
+        Ok(ASTNode::ContinueStatement { span })
+    }
+
+    /// Parses `essayer: ... sauf erreur ["code"] [comme nom]: ... `, with as
+    /// many `sauf erreur` clauses as the source has — each may restrict
+    /// itself to a single error code and/or bind the caught error into a
+    /// variable. Unmatched errors re-propagate out of `execute` instead of
+    /// being swallowed by the first handler.
     fn parse_try_except(&mut self) -> Result<ASTNode, MeowLangError> {
-        let position = self.position_from_token(self.current());
+        let start_span = self.span_from_token(self.current());
         self.advance();
-        
+
         self.expect(TokenType::Colon)?;
         self.skip_newlines();
         self.expect(TokenType::Indent)?;
-        
-        let try_block = self.parse_block()?;
-        
-        self.skip_newlines();
-        self.expect(TokenType::Sauf)?;
-        self.expect(TokenType::Erreur)?;
-        self.expect(TokenType::Colon)?;
+
+        let try_block = self.parse_block();
+        let mut span = match try_block.last() {
+            Some(last) => start_span.merge(last.span()),
+            None => start_span,
+        };
+
         self.skip_newlines();
-        self.expect(TokenType::Indent)?;
-        
-        let except_block = self.parse_block()?;
-        
+
+        let mut handlers = Vec::new();
+        while self.current().token_type == TokenType::Sauf {
+            self.advance();
+            self.expect(TokenType::Erreur)?;
+
+            let code_filter = if self.current().token_type == TokenType::String {
+                let token = self.current().clone();
+                self.advance();
+                match token.value {
+                    TokenValue::String(s) => Some(self.interner.resolve(s).to_string()),
+                    _ => None,
+                }
+            } else {
+                None
+            };
+
+            let binding = if self.current().token_type == TokenType::Comme {
+                self.advance();
+                let name_token = self.expect(TokenType::Identifier)?;
+                match name_token.value {
+                    TokenValue::String(s) => Some(s),
+                    _ => None,
+                }
+            } else {
+                None
+            };
+
+            self.expect(TokenType::Colon)?;
+            self.skip_newlines();
+            self.expect(TokenType::Indent)?;
+
+            let body = self.parse_block();
+            if let Some(last) = body.last() {
+                span = span.merge(last.span());
+            }
+
+            handlers.push(ExceptHandler { code_filter, binding, body });
+            self.skip_newlines();
+        }
+
+        if handlers.is_empty() {
+            return Err(MeowLangError::new(
+                ErrorCatalog::get("E104"),
+                self.filename.clone(),
+                self.current().line,
+                self.current().column,
+            ).with_context(&self.source_lines));
+        }
+
         Ok(ASTNode::TryExcept {
             try_block,
-            except_block,
-            position,
+            handlers,
+            span,
         })
     }
-    
-    fn parse_block(&mut self) -> Result<Vec<ASTNode>, MeowLangError> {
+
+    /// Parses statements until a `Dedent`/`Eof`, recovering in panic mode
+    /// from any statement that fails to parse instead of aborting the
+    /// whole block.
+    fn parse_block(&mut self) -> Vec<ASTNode> {
         let mut statements = Vec::new();
-        
+
         self.skip_newlines();
-        
-        while self.current().token_type != TokenType::Dedent && 
+
+        while !self.halted && self.current().token_type != TokenType::Dedent &&
               self.current().token_type != TokenType::Eof {
-            statements.push(self.parse_statement()?);
+            match self.parse_statement() {
+                Ok(stmt) => statements.push(stmt),
+                Err(error) => {
+                    self.record_error(error);
+                    self.synchronize();
+                }
+            }
             self.skip_newlines();
         }
-        
+
         if self.current().token_type == TokenType::Dedent {
             self.advance();
         }
-        
-        Ok(statements)
+
+        statements
     }
-    
+
     fn parse_expression(&mut self) -> Result<ASTNode, MeowLangError> {
-        self.parse_or()
+        self.parse_pipe()
+    }
+
+    /// Lowest-precedence level: chains of `|>`/`|:`/`|?`/`|&`, e.g.
+    /// `liste |? est_pair |: double`. `|>`/`|:`/`|?` name the function to
+    /// apply on their right, so that side must be a bare function name
+    /// rather than an arbitrary expression — there's no function-value
+    /// syntax in MeowLang to evaluate one from. `|&` concatenates two list
+    /// expressions instead, so its right side parses normally.
+    fn parse_pipe(&mut self) -> Result<ASTNode, MeowLangError> {
+        let mut left = self.parse_or()?;
+
+        loop {
+            let operator = match self.current().token_type {
+                TokenType::PipeApply => "|>",
+                TokenType::PipeMap => "|:",
+                TokenType::PipeFilter => "|?",
+                TokenType::PipeZip => "|&",
+                _ => break,
+            };
+
+            self.advance();
+            let right = if operator == "|&" {
+                self.parse_or()?
+            } else {
+                self.parse_function_name()?
+            };
+            let span = left.span().merge(right.span());
+            left = ASTNode::BinaryOp {
+                left: Box::new(left),
+                operator: operator.to_string(),
+                right: Box::new(right),
+                span,
+            };
+        }
+
+        Ok(left)
     }
-    
+
+    /// Parses a bare function name used as a value — the right-hand side
+    /// of `|>`/`|:`/`|?` and the function argument to `plier`/`fold`.
+    /// Reuses `ASTNode::Identifier` as the carrier for the interned name;
+    /// the compiler recognizes this shape in those specific positions and
+    /// resolves it directly as a callable instead of a variable load.
+    fn parse_function_name(&mut self) -> Result<ASTNode, MeowLangError> {
+        let token = self.current().clone();
+        if token.token_type != TokenType::Identifier {
+            return Err(MeowLangError::new(
+                ErrorCatalog::get("E104"),
+                self.filename.clone(),
+                token.line,
+                token.column,
+            ).with_context(&self.source_lines));
+        }
+
+        let name = match &token.value {
+            TokenValue::String(s) => *s,
+            _ => unreachable!("Identifier tokens always carry an interned name"),
+        };
+        self.advance();
+        Ok(ASTNode::Identifier { name, span: token.span })
+    }
+
     fn parse_or(&mut self) -> Result<ASTNode, MeowLangError> {
         let mut left = self.parse_and()?;
-        
+
         while self.current().token_type == TokenType::Ou {
-            let position = self.position_from_token(self.current());
             self.advance();
             let right = self.parse_and()?;
+            let span = left.span().merge(right.span());
             left = ASTNode::BinaryOp {
                 left: Box::new(left),
                 operator: "ou".to_string(),
                 right: Box::new(right),
-                position,
+                span,
             };
         }
-        
+
         Ok(left)
     }
-    
+
     fn parse_and(&mut self) -> Result<ASTNode, MeowLangError> {
         let mut left = self.parse_not()?;
-        
+
         while self.current().token_type == TokenType::Et {
-            let position = self.position_from_token(self.current());
             self.advance();
             let right = self.parse_not()?;
+            let span = left.span().merge(right.span());
             left = ASTNode::BinaryOp {
                 left: Box::new(left),
                 operator: "et".to_string(),
                 right: Box::new(right),
-                position,
+                span,
             };
         }
-        
+
         Ok(left)
     }
-    
+
     fn parse_not(&mut self) -> Result<ASTNode, MeowLangError> {
         if self.current().token_type == TokenType::Non {
-            let position = self.position_from_token(self.current());
+            let start_span = self.span_from_token(self.current());
             self.advance();
             let operand = self.parse_not()?;
+            let span = start_span.merge(operand.span());
             return Ok(ASTNode::UnaryOp {
                 operator: "non".to_string(),
                 operand: Box::new(operand),
-                position,
+                span,
             });
         }
-        
+
         self.parse_comparison()
     }
-    
+
     fn parse_comparison(&mut self) -> Result<ASTNode, MeowLangError> {
         let mut left = self.parse_additive()?;
-        
+
         while matches!(
             self.current().token_type,
             TokenType::Assign
@@ -503,7 +921,6 @@ impl Parser {
                 | TokenType::LessEqual
                 | TokenType::GreaterEqual
         ) {
-            let position = self.position_from_token(self.current());
             let operator = match self.current().token_type {
                 TokenType::Assign => "=",
                 TokenType::Equal => "=",
@@ -516,23 +933,23 @@ impl Parser {
             };
             self.advance();
             let right = self.parse_additive()?;
+            let span = left.span().merge(right.span());
             left = ASTNode::BinaryOp {
                 left: Box::new(left),
                 operator: operator.to_string(),
                 right: Box::new(right),
-                position,
+                span,
             };
         }
-        
+
         Ok(left)
     }
-    
+
     fn parse_additive(&mut self) -> Result<ASTNode, MeowLangError> {
         let mut left = self.parse_multiplicative()?;
-        
-        while self.current().token_type == TokenType::Plus || 
+
+        while self.current().token_type == TokenType::Plus ||
               self.current().token_type == TokenType::Minus {
-            let position = self.position_from_token(self.current());
             let operator = if self.current().token_type == TokenType::Plus {
                 "+"
             } else {
@@ -540,25 +957,25 @@ impl Parser {
             };
             self.advance();
             let right = self.parse_multiplicative()?;
+            let span = left.span().merge(right.span());
             left = ASTNode::BinaryOp {
                 left: Box::new(left),
                 operator: operator.to_string(),
                 right: Box::new(right),
-                position,
+                span,
             };
         }
-        
+
         Ok(left)
     }
-    
+
     fn parse_multiplicative(&mut self) -> Result<ASTNode, MeowLangError> {
         let mut left = self.parse_power()?;
-        
+
         while matches!(
             self.current().token_type,
             TokenType::Multiply | TokenType::Divide | TokenType::FloorDiv | TokenType::Modulo
         ) {
-            let position = self.position_from_token(self.current());
             let operator = match self.current().token_type {
                 TokenType::Multiply => "*",
                 TokenType::Divide => "/",
@@ -568,115 +985,128 @@ impl Parser {
             };
             self.advance();
             let right = self.parse_power()?;
+            let span = left.span().merge(right.span());
             left = ASTNode::BinaryOp {
                 left: Box::new(left),
                 operator: operator.to_string(),
                 right: Box::new(right),
-                position,
+                span,
             };
         }
-        
+
         Ok(left)
     }
-    
+
     fn parse_power(&mut self) -> Result<ASTNode, MeowLangError> {
-        let mut left = self.parse_unary()?;
-        
+        let left = self.parse_unary()?;
+
         if self.current().token_type == TokenType::Power {
-            let position = self.position_from_token(self.current());
             self.advance();
             let right = self.parse_power()?;
-            left = ASTNode::BinaryOp {
+            let span = left.span().merge(right.span());
+            return Ok(ASTNode::BinaryOp {
                 left: Box::new(left),
                 operator: "**".to_string(),
                 right: Box::new(right),
-                position,
-            };
+                span,
+            });
         }
-        
+
         Ok(left)
     }
-    
+
     fn parse_unary(&mut self) -> Result<ASTNode, MeowLangError> {
         if self.current().token_type == TokenType::Minus {
-            let position = self.position_from_token(self.current());
+            let start_span = self.span_from_token(self.current());
             self.advance();
             let operand = self.parse_unary()?;
+            let span = start_span.merge(operand.span());
             return Ok(ASTNode::UnaryOp {
                 operator: "-".to_string(),
                 operand: Box::new(operand),
-                position,
+                span,
             });
         }
-        
+
         self.parse_postfix()
     }
-    
+
     fn parse_postfix(&mut self) -> Result<ASTNode, MeowLangError> {
         let mut expr = self.parse_primary()?;
-        
+
         loop {
             match self.current().token_type {
                 TokenType::LParen => {
-                    if let ASTNode::Identifier { name, .. } = expr {
-                        let position = self.position_from_token(self.current());
+                    if let ASTNode::Identifier { name, span: name_span } = expr {
+                        let open_paren = self.current().clone();
                         self.advance();
-                        
+
                         let mut arguments = Vec::new();
-                        
-                        while self.current().token_type != TokenType::RParen {
+
+                        while self.current().token_type != TokenType::RParen
+                            && self.current().token_type != TokenType::Eof {
                             arguments.push(self.parse_expression()?);
-                            
+
                             if self.current().token_type == TokenType::Comma {
                                 self.advance();
                             }
                         }
-                        
-                        self.expect(TokenType::RParen)?;
-                        
+
+                        if self.current().token_type != TokenType::RParen {
+                            return Err(MeowLangError::new(
+                                ErrorCatalog::get("E105"),
+                                self.filename.clone(),
+                                open_paren.line,
+                                open_paren.column,
+                            ).with_context(&self.source_lines));
+                        }
+                        let close = self.expect(TokenType::RParen)?;
+                        let span = name_span.merge(&close.span);
+
                         expr = ASTNode::FunctionCall {
                             name,
                             arguments,
-                            position,
+                            span,
+                            leading_trivia: Vec::new(),
                         };
                     } else {
                         break;
                     }
                 },
                 TokenType::LBracket => {
-                    let position = self.position_from_token(self.current());
                     self.advance();
                     let index = self.parse_expression()?;
-                    self.expect(TokenType::RBracket)?;
-                    
+                    let close = self.expect(TokenType::RBracket)?;
+                    let span = expr.span().merge(&close.span);
+
                     expr = ASTNode::IndexAccess {
                         object: Box::new(expr),
                         index: Box::new(index),
-                        position,
+                        span,
                     };
                 },
                 _ => break,
             }
         }
-        
+
         Ok(expr)
     }
-    
+
     fn parse_primary(&mut self) -> Result<ASTNode, MeowLangError> {
         let token = self.current().clone();
-        let position = self.position_from_token(&token);
-        
+        let span = self.span_from_token(&token);
+
         match &token.token_type {
             TokenType::Number => {
                 self.advance();
                 match &token.value {
                     TokenValue::Number(n) => Ok(ASTNode::Literal {
                         value: LiteralValue::Number(*n),
-                        position,
+                        span,
                     }),
                     TokenValue::Integer(i) => Ok(ASTNode::Literal {
                         value: LiteralValue::Integer(*i),
-                        position,
+                        span,
                     }),
                     _ => unreachable!(),
                 }
@@ -685,40 +1115,96 @@ impl Parser {
                 self.advance();
                 if let TokenValue::String(s) = &token.value {
                     Ok(ASTNode::Literal {
-                        value: LiteralValue::String(s.clone()),
-                        position,
+                        value: LiteralValue::String(*s),
+                        span,
                     })
                 } else {
                     unreachable!()
                 }
             },
+            TokenType::InterpolatedString => {
+                self.advance();
+                let segments = if let TokenValue::Interpolation(segments) = &token.value {
+                    segments.clone()
+                } else {
+                    unreachable!()
+                };
+
+                let mut parts = Vec::with_capacity(segments.len());
+                for segment in segments {
+                    match segment {
+                        InterpolationSegment::Literal(s) => parts.push(InterpolationPart::Literal(s)),
+                        InterpolationSegment::Expr(source) => {
+                            let expr = self.parse_interpolated_expr(&source, &token)?;
+                            parts.push(InterpolationPart::Expr(Box::new(expr)));
+                        },
+                    }
+                }
+
+                Ok(ASTNode::Interpolation { parts, span })
+            },
             TokenType::Boolean => {
                 self.advance();
                 if let TokenValue::Boolean(b) = &token.value {
                     Ok(ASTNode::Literal {
                         value: LiteralValue::Boolean(*b),
-                        position,
+                        span,
                     })
                 } else {
                     unreachable!()
                 }
             },
-            TokenType::Identifier => {
+            TokenType::Char => {
                 self.advance();
-                if let TokenValue::String(s) = &token.value {
-                    Ok(ASTNode::Identifier {
-                        name: s.clone(),
-                        position,
+                if let TokenValue::Char(c) = &token.value {
+                    Ok(ASTNode::Literal {
+                        value: LiteralValue::Char(*c),
+                        span,
                     })
                 } else {
                     unreachable!()
                 }
             },
+            TokenType::Identifier => {
+                self.advance();
+                let name = if let TokenValue::String(s) = &token.value {
+                    *s
+                } else {
+                    unreachable!()
+                };
+
+                // Prefix-keyword builtins (`minuscule`, `aleatoire`, ...)
+                // share the plain `Identifier` token with every other
+                // name; `builtins::lookup` is what tells them apart, so
+                // adding one is a table edit instead of a new TokenType
+                // and a new match arm here.
+                match builtins::lookup(self.interner.resolve(name)) {
+                    Some(Arity::Fixed(count)) => {
+                        let mut arguments = Vec::with_capacity(count);
+                        for _ in 0..count {
+                            arguments.push(self.parse_expression()?);
+                        }
+                        let span = match arguments.last() {
+                            Some(last) => span.merge(last.span()),
+                            None => span,
+                        };
+                        Ok(ASTNode::FunctionCall { name, arguments, span, leading_trivia: token.leading_trivia.clone() })
+                    },
+                    Some(Arity::InfixRange) => {
+                        let start = self.parse_expression()?;
+                        self.expect(TokenType::A)?;
+                        let end = self.parse_expression()?;
+                        let span = span.merge(end.span());
+                        Ok(ASTNode::FunctionCall { name, arguments: vec![start, end], span, leading_trivia: token.leading_trivia.clone() })
+                    },
+                    None => Ok(ASTNode::Identifier { name, span }),
+                }
+            },
             TokenType::Compteur => {
                 self.advance();
                 Ok(ASTNode::Identifier {
-                    name: "compteur".to_string(),
-                    position,
+                    name: self.interner.intern("compteur"),
+                    span,
                 })
             },
             TokenType::LParen => {
@@ -730,31 +1216,58 @@ impl Parser {
             TokenType::Liste => {
                 self.advance();
                 self.expect(TokenType::LParen)?;
-                
+
                 let mut elements = Vec::new();
-                
+
                 while self.current().token_type != TokenType::RParen {
                     elements.push(self.parse_expression()?);
-                    
+
                     if self.current().token_type == TokenType::Comma {
                         self.advance();
                     }
                 }
-                
-                self.expect(TokenType::RParen)?;
-                
+
+                let close = self.expect(TokenType::RParen)?;
+                let span = span.merge(&close.span);
+
                 Ok(ASTNode::ListNode {
                     elements,
-                    position,
+                    span,
+                    leading_trivia: token.leading_trivia.clone(),
+                })
+            },
+            TokenType::Dictionnaire => {
+                self.advance();
+                self.expect(TokenType::LParen)?;
+
+                let mut pairs = Vec::new();
+
+                while self.current().token_type != TokenType::RParen {
+                    let key = self.parse_expression()?;
+                    self.expect(TokenType::Colon)?;
+                    let value = self.parse_expression()?;
+                    pairs.push((key, value));
+
+                    if self.current().token_type == TokenType::Comma {
+                        self.advance();
+                    }
+                }
+
+                let close = self.expect(TokenType::RParen)?;
+                let span = span.merge(&close.span);
+
+                Ok(ASTNode::DictNode {
+                    pairs,
+                    span,
                 })
             },
             TokenType::Demander => {
                 self.advance();
-                
+
                 let type_token = self.current().clone();
                 let input_type = if type_token.token_type == TokenType::Identifier {
                     if let TokenValue::String(s) = &type_token.value {
-                        let lower = s.to_lowercase();
+                        let lower = self.interner.resolve(*s).to_lowercase();
                         if lower == "texte" || lower == "nombre" {
                             lower
                         } else {
@@ -782,50 +1295,15 @@ impl Parser {
                     ).with_context(&self.source_lines));
                 };
                 self.advance();
-                
+
                 let prompt = self.parse_expression()?;
-                
+                let span = span.merge(prompt.span());
+
                 Ok(ASTNode::FunctionCall {
-                    name: format!("demander_{}", input_type),
+                    name: self.interner.intern(&format!("demander_{}", input_type)),
                     arguments: vec![prompt],
-                    position,
-                })
-            },
-            TokenType::Minuscule | TokenType::Majuscule | TokenType::Longueur | 
-            TokenType::Aleatoire | TokenType::Sqrt | TokenType::Abs | 
-            TokenType::Round | TokenType::Floor | TokenType::Ceil | TokenType::Attendre => {
-                let func_name = match token.token_type {
-                    TokenType::Minuscule => "minuscule",
-                    TokenType::Majuscule => "majuscule",
-                    TokenType::Longueur => "longueur",
-                    TokenType::Aleatoire => "aleatoire",
-                    TokenType::Sqrt => "sqrt",
-                    TokenType::Abs => "abs",
-                    TokenType::Round => "round",
-                    TokenType::Floor => "floor",
-                    TokenType::Ceil => "ceil",
-                    TokenType::Attendre => "attendre",
-                    _ => "",
-                };
-                
-                self.advance();
-                
-                let mut arguments = Vec::new();
-                
-                if token.token_type == TokenType::Aleatoire {
-                    let start = self.parse_expression()?;
-                    self.expect(TokenType::A)?;
-                    let end = self.parse_expression()?;
-                    arguments.push(start);
-                    arguments.push(end);
-                } else {
-                    arguments.push(self.parse_expression()?);
-                }
-                
-                Ok(ASTNode::FunctionCall {
-                    name: func_name.to_string(),
-                    arguments,
-                    position,
+                    span,
+                    leading_trivia: token.leading_trivia.clone(),
                 })
             },
             _ => {
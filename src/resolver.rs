@@ -0,0 +1,378 @@
+//! A second static pass over the parsed `Ast`, run alongside `Analyzer` (see
+//! `lib::run`). Where `Analyzer` pre-collects every name a function ever
+//! assigns (`collect_assigned`) before checking a single read, `Resolver`
+//! walks the tree once, in source order, growing each function's bindings
+//! as it goes — so it can tell a read that comes *before* the matching
+//! assignment (use-before-definition) from one that never has a matching
+//! assignment at all (already `Analyzer`'s job, via `E200`; `Resolver`
+//! leaves that case alone so the two passes don't both report the same
+//! mistake). It also gives `compteur` — the implicit loop counter a
+//! `RepeatLoop` synthesizes (see `compiler.rs`) — a real, scoped lifetime
+//! instead of letting every use of it fall through as undefined, and flags
+//! a local that's assigned but never read.
+//!
+//! This is modeled on the `depth`-annotation resolver from the Crafting
+//! Interpreters "resolver" chapter, but deliberately stops short of
+//! reproducing its headline feature: annotating `Identifier`/`Assignment`
+//! with a scope `depth` so the interpreter can jump straight to the right
+//! environment instead of searching outward one scope at a time. That
+//! problem doesn't exist here — `Compiler`'s `SlotTable` already assigns
+//! every local a fixed stack slot at compile time (see `compiler.rs`), so a
+//! `Value` read is already O(1) with no environment chain to walk. Carrying
+//! a `depth` field nobody reads through `Parser`, `Optimizer`, `Analyzer`
+//! and `Compiler` — every pass that matches `Identifier`/`Assignment`
+//! today — would be dead weight with no payoff in this VM, so the scope
+//! stack below stays a private implementation detail of the walk and this
+//! module reports only what it's actually for: diagnostics.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::ast::{ASTNode, InterpolationPart, Span};
+use crate::error::{DiagnosticSink, ErrorCatalog, MeowLangError};
+use crate::interner::{Interner, Symbol};
+
+/// One function's (or the top-level program's) flat binding table — mirrors
+/// the granularity `Compiler`'s `SlotTable` actually allocates at, so a name
+/// assigned inside an `si`/`tant que`/`essayer` block is visible to a
+/// sibling statement after it ends, exactly as it is at runtime.
+struct FunctionScope {
+    bindings: HashMap<Symbol, BindingInfo>,
+}
+
+struct BindingInfo {
+    span: Span,
+    used: bool,
+}
+
+impl FunctionScope {
+    fn new() -> Self {
+        FunctionScope { bindings: HashMap::new() }
+    }
+}
+
+pub struct Resolver<'a> {
+    interner: &'a Interner,
+    filename: String,
+    source_lines: Vec<String>,
+    sink: DiagnosticSink,
+    warnings: Vec<MeowLangError>,
+    functions: HashMap<Symbol, usize>,
+    scopes: Vec<FunctionScope>,
+    /// Every name the current function (or the top-level program) assigns
+    /// *anywhere* in its body, computed up front the same way
+    /// `Analyzer::collect_assigned` does. `scopes` only holds what's been
+    /// bound so far in the walk; this is what tells `check_read` a name
+    /// missing from `scopes` is merely used too early rather than never
+    /// defined at all.
+    eventual: Vec<HashSet<Symbol>>,
+    /// How many `RepeatLoop` bodies currently enclose the walk — `compteur`
+    /// only resolves while this is above zero.
+    repeat_depth: usize,
+    compteur: Symbol,
+}
+
+impl<'a> Resolver<'a> {
+    pub fn new(interner: &'a mut Interner, filename: String, source_lines: Vec<String>) -> Self {
+        let compteur = interner.intern("compteur");
+        Resolver {
+            interner: &*interner,
+            filename,
+            source_lines,
+            sink: DiagnosticSink::default(),
+            warnings: Vec::new(),
+            functions: HashMap::new(),
+            scopes: Vec::new(),
+            eventual: Vec::new(),
+            repeat_depth: 0,
+            compteur,
+        }
+    }
+
+    /// Resolves `program`, returning the errors that should stop the run
+    /// (use-before-definition, `compteur` misuse) separately from the
+    /// unused-local warnings, which are worth printing but never worth
+    /// halting a working program over.
+    pub fn resolve(mut self, program: &ASTNode) -> (Vec<MeowLangError>, Vec<MeowLangError>) {
+        let statements = match program {
+            ASTNode::Program { statements, .. } => statements,
+            _ => return (Vec::new(), Vec::new()),
+        };
+
+        self.collect_functions(statements);
+
+        let mut eventual = HashSet::new();
+        self.collect_assigned(statements, &mut eventual);
+        self.eventual.push(eventual);
+        self.scopes.push(FunctionScope::new());
+        self.walk_block(statements);
+        let top = self.scopes.pop().expect("pushed immediately above");
+        self.eventual.pop();
+        self.report_unused(top);
+
+        (self.sink.errors, self.warnings)
+    }
+
+    /// Gathers every name `statements` assigns anywhere in its body (not a
+    /// flow analysis — just "is this name ever bound here"), mirroring
+    /// `Analyzer::collect_assigned`. Stops at a nested `FunctionDef`, which
+    /// gets its own `eventual` set when the walk reaches it.
+    fn collect_assigned(&self, statements: &[ASTNode], scope: &mut HashSet<Symbol>) {
+        for stmt in statements {
+            match stmt {
+                ASTNode::Assignment { name, .. } => {
+                    scope.insert(*name);
+                },
+                ASTNode::IfStatement { then_block, elif_blocks, else_block, .. } => {
+                    self.collect_assigned(then_block, scope);
+                    for (_, body) in elif_blocks {
+                        self.collect_assigned(body, scope);
+                    }
+                    if let Some(body) = else_block {
+                        self.collect_assigned(body, scope);
+                    }
+                },
+                ASTNode::WhileLoop { body, .. } | ASTNode::RepeatLoop { body, .. } => {
+                    self.collect_assigned(body, scope);
+                },
+                ASTNode::ForEachLoop { iterator, body, .. } => {
+                    scope.insert(*iterator);
+                    self.collect_assigned(body, scope);
+                },
+                ASTNode::TryExcept { try_block, handlers, .. } => {
+                    self.collect_assigned(try_block, scope);
+                    for handler in handlers {
+                        if let Some(binding) = handler.binding {
+                            scope.insert(binding);
+                        }
+                        self.collect_assigned(&handler.body, scope);
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    /// Hoists every `FunctionDef`, mirroring `Analyzer::collect_functions`
+    /// (in turn mirroring `Compiler::hoist_functions`): a call is never
+    /// "use before definition" just because the call appears first
+    /// textually.
+    fn collect_functions(&mut self, statements: &[ASTNode]) {
+        for stmt in statements {
+            match stmt {
+                ASTNode::FunctionDef { name, parameters, body, .. } => {
+                    self.functions.insert(*name, parameters.len());
+                    self.collect_functions(body);
+                },
+                ASTNode::IfStatement { then_block, elif_blocks, else_block, .. } => {
+                    self.collect_functions(then_block);
+                    for (_, body) in elif_blocks {
+                        self.collect_functions(body);
+                    }
+                    if let Some(body) = else_block {
+                        self.collect_functions(body);
+                    }
+                },
+                ASTNode::WhileLoop { body, .. }
+                | ASTNode::RepeatLoop { body, .. }
+                | ASTNode::ForEachLoop { body, .. } => self.collect_functions(body),
+                ASTNode::TryExcept { try_block, handlers, .. } => {
+                    self.collect_functions(try_block);
+                    for handler in handlers {
+                        self.collect_functions(&handler.body);
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    fn walk_block(&mut self, statements: &[ASTNode]) {
+        for stmt in statements {
+            self.walk_stmt(stmt);
+        }
+    }
+
+    fn walk_stmt(&mut self, stmt: &ASTNode) {
+        match stmt {
+            ASTNode::ExpressionStatement { expression, .. } => self.walk_expr(expression),
+            ASTNode::Assignment { name, value, .. } => {
+                self.walk_expr(value);
+                self.bind(*name, stmt.span().clone());
+            },
+            ASTNode::IfStatement { condition, then_block, elif_blocks, else_block, .. } => {
+                self.walk_expr(condition);
+                self.walk_block(then_block);
+                for (elif_condition, body) in elif_blocks {
+                    self.walk_expr(elif_condition);
+                    self.walk_block(body);
+                }
+                if let Some(body) = else_block {
+                    self.walk_block(body);
+                }
+            },
+            ASTNode::WhileLoop { condition, body, .. } => {
+                self.walk_expr(condition);
+                self.walk_block(body);
+            },
+            ASTNode::RepeatLoop { count, body, .. } => {
+                self.walk_expr(count);
+                self.repeat_depth += 1;
+                self.walk_block(body);
+                self.repeat_depth -= 1;
+            },
+            ASTNode::ForEachLoop { iterator, iterable, body, .. } => {
+                self.walk_expr(iterable);
+                self.bind(*iterator, stmt.span().clone());
+                self.walk_block(body);
+            },
+            ASTNode::FunctionDef { parameters, body, .. } => {
+                let mut eventual: HashSet<Symbol> = parameters.iter().cloned().collect();
+                self.collect_assigned(body, &mut eventual);
+                self.eventual.push(eventual);
+
+                let mut scope = FunctionScope::new();
+                for parameter in parameters {
+                    // Parameters are routine to leave unused (many handlers
+                    // ignore one), so they're pre-marked `used` and never
+                    // show up in `report_unused` below.
+                    scope.bindings.insert(*parameter, BindingInfo { span: stmt.span().clone(), used: true });
+                }
+                self.scopes.push(scope);
+
+                self.walk_block(body);
+
+                let scope = self.scopes.pop().expect("pushed immediately above");
+                self.eventual.pop();
+                self.report_unused(scope);
+            },
+            ASTNode::ReturnStatement { value, .. } => {
+                if let Some(value) = value {
+                    self.walk_expr(value);
+                }
+            },
+            ASTNode::BreakStatement { .. } | ASTNode::ContinueStatement { .. } => {},
+            ASTNode::IndexAssignment { object, index, value, .. } => {
+                self.walk_expr(object);
+                self.walk_expr(index);
+                self.walk_expr(value);
+            },
+            ASTNode::TryExcept { try_block, handlers, .. } => {
+                self.walk_block(try_block);
+                for handler in handlers {
+                    if let Some(binding) = handler.binding {
+                        self.bind(binding, stmt.span().clone());
+                    }
+                    self.walk_block(&handler.body);
+                }
+            },
+            other => self.walk_expr(other),
+        }
+    }
+
+    fn walk_expr(&mut self, expr: &ASTNode) {
+        match expr {
+            ASTNode::Identifier { name, span } => self.check_read(*name, span),
+            ASTNode::BinaryOp { left, right, .. } => {
+                self.walk_expr(left);
+                self.walk_expr(right);
+            },
+            ASTNode::UnaryOp { operand, .. } => self.walk_expr(operand),
+            ASTNode::FunctionCall { arguments, .. } => {
+                for argument in arguments {
+                    self.walk_expr(argument);
+                }
+            },
+            ASTNode::ListNode { elements, .. } => {
+                for element in elements {
+                    self.walk_expr(element);
+                }
+            },
+            ASTNode::DictNode { pairs, .. } => {
+                for (key, value) in pairs {
+                    self.walk_expr(key);
+                    self.walk_expr(value);
+                }
+            },
+            ASTNode::IndexAccess { object, index, .. } => {
+                self.walk_expr(object);
+                self.walk_expr(index);
+            },
+            ASTNode::Interpolation { parts, .. } => {
+                for part in parts {
+                    if let InterpolationPart::Expr(expr) = part {
+                        self.walk_expr(expr);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    /// Inserted into the innermost (current function's) scope — this walk
+    /// never pushes a scope for an `si`/`tant que`/`essayer` block, so a
+    /// binding made inside one is already visible to whatever comes after
+    /// it, matching `SlotTable`'s per-function (not per-block) slots.
+    fn bind(&mut self, name: Symbol, span: Span) {
+        let scope = self.scopes.last_mut().expect("resolve always pushes the top-level scope first");
+        scope.bindings.entry(name).or_insert(BindingInfo { span, used: false });
+    }
+
+    fn check_read(&mut self, name: Symbol, span: &Span) {
+        if name == self.compteur {
+            if self.repeat_depth == 0 {
+                self.push_error("E201", span, &[("var_name", "compteur".to_string())]);
+            }
+            return;
+        }
+
+        if let Some(scope) = self.scopes.last_mut() {
+            if let Some(binding) = scope.bindings.get_mut(&name) {
+                binding.used = true;
+                return;
+            }
+        }
+
+        // Not bound yet at this point in the walk. Only worth a diagnostic
+        // here if it *will* be bound later in this same function — a name
+        // that's never bound anywhere is `Analyzer`'s `E200` to report, not
+        // a duplicate of it here.
+        if self.functions.contains_key(&name) || self.is_builtin(name) {
+            return;
+        }
+        if self.eventual.last().map_or(false, |set| set.contains(&name)) {
+            let var_name = self.interner.resolve(name).to_string();
+            self.push_error("E204", span, &[("var_name", var_name)]);
+        }
+    }
+
+    fn is_builtin(&self, name: Symbol) -> bool {
+        crate::analyzer::is_builtin_function(self.interner.resolve(name))
+    }
+
+    fn report_unused(&mut self, scope: FunctionScope) {
+        for (name, binding) in scope.bindings {
+            if binding.used {
+                continue;
+            }
+            let var_name = self.interner.resolve(name).to_string();
+            let error = MeowLangError::new(
+                ErrorCatalog::get("E203"),
+                self.filename.clone(),
+                binding.span.start.line,
+                binding.span.start.column,
+            )
+            .with_context(&self.source_lines)
+            .with_extra("var_name".to_string(), var_name);
+            self.warnings.push(error);
+        }
+    }
+
+    fn push_error(&mut self, code: &str, span: &Span, extras: &[(&str, String)]) {
+        let mut error = MeowLangError::new(ErrorCatalog::get(code), self.filename.clone(), span.start.line, span.start.column)
+            .with_context(&self.source_lines);
+        for (key, value) in extras {
+            error = error.with_extra((*key).to_string(), value.clone());
+        }
+        self.sink.push(error);
+    }
+}
@@ -1,4 +1,7 @@
 use std::fmt;
+use std::ops::Range;
+use crate::ast::{Position, Span};
+use crate::interner::Symbol;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TokenType {
@@ -24,38 +27,35 @@ pub enum TokenType {
     
     Fonction,
     Retour,
-    
+    Casser,
+    Continuer,
+
     Liste,
     Dictionnaire,
     
     Essayer,
     Sauf,
     Erreur,
+    Comme,
     
     Importer,
     
-    Minuscule,
-    Majuscule,
-    Longueur,
-    Remplacer,
-    Contient,
-    
-    Aleatoire,
-    Sqrt,
-    Abs,
-    Round,
-    Floor,
-    Ceil,
-    
     Ouvrir,
     Lire,
     Fermer,
-    
-    Attendre,
-    
+
     Identifier,
     String,
+    /// A string literal containing at least one `{expr}` segment, e.g.
+    /// `"Bonjour {nom} !"`. Its `TokenValue` is `Interpolation`, carrying
+    /// the raw literal/expr pieces for `Parser` to rebuild and parse.
+    InterpolatedString,
     Number,
+    /// A single-quoted character literal, e.g. `'c'` — exactly one logical
+    /// character after escape processing, distinct from `String`/
+    /// `InterpolatedString` (which own the double-quote family). Its
+    /// `TokenValue` is `Char`.
+    Char,
     Boolean,
     
     Plus,
@@ -73,10 +73,28 @@ pub enum TokenType {
     GreaterThan,
     LessEqual,
     GreaterEqual,
+
+    /// `+=` — desugars to `nom = nom + valeur` in `Parser::parse_assignment`.
+    PlusAssign,
+    /// `-=` — desugars to `nom = nom - valeur`.
+    MinusAssign,
+    /// `*=` — desugars to `nom = nom * valeur`.
+    MultiplyAssign,
+    /// `/=` — desugars to `nom = nom / valeur`.
+    DivideAssign,
     
     Et,
     Ou,
     Non,
+
+    /// `|>` — apply the right-hand function to the whole left value.
+    PipeApply,
+    /// `|:` — map the right-hand function over each element of a list.
+    PipeMap,
+    /// `|?` — filter a list, keeping elements where the function is truthy.
+    PipeFilter,
+    /// `|&` — concatenate two lists.
+    PipeZip,
     
     A,
     
@@ -102,12 +120,27 @@ impl fmt::Display for TokenType {
     }
 }
 
+/// One piece of an interpolated string literal, in source order. `Expr`
+/// holds the *raw source text* between the braces — the lexer doesn't try
+/// to tokenize/parse it; `Parser` re-lexes and parses each one with
+/// `parse_expression` when it builds the `Interpolation` AST node.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InterpolationSegment {
+    Literal(Symbol),
+    Expr(String),
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum TokenValue {
     None,
-    String(String),
+    /// Interned text: both identifier names and string literal contents
+    /// go through the `Interner`, so equal text always means equal `Symbol`.
+    String(Symbol),
+    /// The pieces of an `InterpolatedString` token — see `InterpolationSegment`.
+    Interpolation(Vec<InterpolationSegment>),
     Number(f64),
     Integer(i64),
+    Char(char),
     Boolean(bool),
     Indent(usize),
 }
@@ -118,20 +151,57 @@ pub struct Token {
     pub value: TokenValue,
     pub line: usize,
     pub column: usize,
+    pub span: Span,
+    /// Byte offsets into the original source string this token was
+    /// scanned from — `span`'s line/column counterpart, for tooling that
+    /// wants to slice or address source text directly (a formatter, an
+    /// LSP). `Lexer` is the only thing that fills this in correctly: it
+    /// starts every token out at `0..0` here and has `push_token` patch
+    /// in the real range (see `Lexer::start_token`/`Lexer::slice`), since
+    /// a bare `Position` has no byte offset to build it from.
+    pub byte_range: Range<usize>,
+    /// Comment lines the lexer skipped immediately before this token,
+    /// verbatim text with the leading `#` stripped, oldest first. Empty
+    /// for the overwhelming majority of tokens — only populated when a
+    /// user comment sat directly above whatever this token starts.
+    /// `meowfmt` (see `formatter.rs`) reattaches these above the token
+    /// it prints; nothing else in the pipeline reads this field, so a
+    /// plain source program compiles identically whether or not it's set.
+    pub leading_trivia: Vec<String>,
 }
 
 impl Token {
-    pub fn new(token_type: TokenType, value: TokenValue, line: usize, column: usize) -> Self {
+    /// `line`/`column` are kept alongside `span` for back-compat: most of
+    /// the parser's error reporting still just wants "where did this
+    /// token start".
+    pub fn new(token_type: TokenType, value: TokenValue, span: Span) -> Self {
         Token {
             token_type,
             value,
-            line,
-            column,
+            line: span.start.line,
+            column: span.start.column,
+            span,
+            byte_range: 0..0,
+            leading_trivia: Vec::new(),
         }
     }
-    
-    pub fn simple(token_type: TokenType, line: usize, column: usize) -> Self {
-        Token::new(token_type, TokenValue::None, line, column)
+
+    pub fn simple(token_type: TokenType, span: Span) -> Self {
+        Token::new(token_type, TokenValue::None, span)
+    }
+
+    /// Builds a token spanning a single point, when the lexer only knows
+    /// where it starts (e.g. synthetic tokens). Has no byte offset to
+    /// work with either, so `byte_range` stays `0..0`.
+    pub fn at(token_type: TokenType, value: TokenValue, line: usize, column: usize) -> Self {
+        Token::new(token_type, value, Span::point(Position::new(line, column)))
+    }
+
+    /// The byte-offset range into the original source this token spans —
+    /// named distinctly from the `span` field (which holds the line/column
+    /// `Span`) so the two aren't confused at a call site.
+    pub fn byte_span(&self) -> Range<usize> {
+        self.byte_range.clone()
     }
 }
 
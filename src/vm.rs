@@ -0,0 +1,1072 @@
+use std::collections::VecDeque;
+use std::panic;
+use std::sync::{mpsc, Arc, Condvar, Mutex, OnceLock};
+use std::thread;
+use std::time::Duration;
+
+use crate::ast::Position;
+use crate::compiler::{CmpOp, CompiledProgram, HandlerSpec, Instr};
+use crate::error::{ErrorCatalog, MeowLangError};
+use crate::interner::{Interner, Symbol};
+use crate::native::{NativeContext, NativeRegistry};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    String(String),
+    Number(f64),
+    Integer(i64),
+    /// Always stored reduced via `gcd`, with a positive denominator — see
+    /// `VM::make_rational`. An integer result collapses back to `Integer`
+    /// rather than a `Rational` with `den: 1`.
+    Rational { num: i64, den: i64 },
+    Complex { re: f64, im: f64 },
+    Boolean(bool),
+    List(Vec<Value>),
+    /// Minimal key/value container, for now only produced internally by
+    /// `attrape` to bind a caught error's `code`/`message`/`ligne`/`colonne`.
+    /// Order-preserving like `List` rather than hashed, since lookups are
+    /// by a handful of known keys rather than arbitrary user keys.
+    Dict(Vec<(String, Value)>),
+    None,
+}
+
+impl Value {
+    pub(crate) fn to_string(&self) -> String {
+        match self {
+            Value::String(s) => s.clone(),
+            Value::Number(n) => n.to_string(),
+            Value::Integer(i) => i.to_string(),
+            Value::Rational { num, den } => format!("{}/{}", num, den),
+            Value::Complex { re, im } => {
+                if *im >= 0.0 {
+                    format!("{}+{}i", re, im)
+                } else {
+                    format!("{}{}i", re, im)
+                }
+            },
+            Value::Boolean(b) => if *b { "vrai" } else { "faux" }.to_string(),
+            Value::List(items) => {
+                let strs: Vec<String> = items.iter().map(|v| v.to_string()).collect();
+                format!("[{}]", strs.join(", "))
+            },
+            Value::Dict(pairs) => {
+                let strs: Vec<String> = pairs.iter().map(|(k, v)| format!("{}: {}", k, v.to_string())).collect();
+                format!("{{{}}}", strs.join(", "))
+            },
+            Value::None => "".to_string(),
+        }
+    }
+
+    pub(crate) fn to_number(&self) -> Result<f64, String> {
+        match self {
+            Value::Number(n) => Ok(*n),
+            Value::Integer(i) => Ok(*i as f64),
+            Value::Rational { num, den } => Ok(*num as f64 / *den as f64),
+            Value::String(s) => s.parse::<f64>().map_err(|_| format!("Cannot convert '{}' to number", s)),
+            Value::Complex { .. } => Err("Cannot convert a complex number to a real number".to_string()),
+            _ => Err("Cannot convert to number".to_string()),
+        }
+    }
+
+    fn to_bool(&self) -> bool {
+        match self {
+            Value::Boolean(b) => *b,
+            Value::Integer(i) => *i != 0,
+            Value::Number(n) => *n != 0.0,
+            Value::Rational { num, .. } => *num != 0,
+            Value::Complex { re, im } => *re != 0.0 || *im != 0.0,
+            Value::String(s) => !s.is_empty(),
+            Value::List(l) => !l.is_empty(),
+            Value::Dict(pairs) => !pairs.is_empty(),
+            Value::None => false,
+        }
+    }
+
+    /// Returns `(numerator, denominator)` for `Integer`/`Rational` values,
+    /// `None` for anything else — used to spot the exact-arithmetic path in
+    /// the `BinaryOp` handlers before falling back to floats.
+    fn as_rational(&self) -> Option<(i64, i64)> {
+        match self {
+            Value::Integer(i) => Some((*i, 1)),
+            Value::Rational { num, den } => Some((*num, *den)),
+            _ => None,
+        }
+    }
+
+    fn values_equal(left: &Value, right: &Value) -> bool {
+        match (left, right) {
+            (Value::String(l), Value::String(r)) => l == r,
+            (Value::Integer(l), Value::Integer(r)) => l == r,
+            (Value::Number(l), Value::Number(r)) => (l - r).abs() < f64::EPSILON,
+            (Value::Boolean(l), Value::Boolean(r)) => l == r,
+            (Value::None, Value::None) => true,
+            (Value::Complex { re: lre, im: lim }, Value::Complex { re: rre, im: rim }) => {
+                (lre - rre).abs() < f64::EPSILON && (lim - rim).abs() < f64::EPSILON
+            },
+            (Value::Integer(_) | Value::Rational { .. }, Value::Integer(_) | Value::Rational { .. }) => {
+                let (ln, ld) = left.as_rational().unwrap();
+                let (rn, rd) = right.as_rational().unwrap();
+                ln * rd == rn * ld
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Greatest common divisor, always non-negative; `gcd(0, 0) == 1` so it's
+/// always safe to divide a rational's num/den by it.
+pub(crate) fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    if a == 0 { 1 } else { a }
+}
+
+/// One call-frame on the VM's explicit call stack: its own bytecode chunk
+/// (the program's top-level code, or a `CompiledFunction`'s), its own
+/// locals slab, and the try-regions currently guarding it.
+struct Frame<'p> {
+    code: &'p [Instr],
+    positions: &'p [Position],
+    slot_names: &'p [Symbol],
+    pc: usize,
+    locals: Vec<Option<Value>>,
+    /// One entry per `essayer` block this frame is currently inside,
+    /// innermost last.
+    try_stack: Vec<TryRegion>,
+}
+
+/// A currently-open `essayer` block: its `sauf erreur` handlers plus the
+/// operand-stack length to restore to before dispatching to whichever one
+/// matches.
+struct TryRegion {
+    handlers: Vec<HandlerSpec>,
+    stack_len: usize,
+}
+
+/// How many call frames (user functions plus the top-level chunk) may be
+/// open at once before `Instr::Call`/`call_function` raise `E602` instead
+/// of growing the frame stack further — guards the native stack against a
+/// MeowLang recursive function with no base case.
+const MAX_CALL_DEPTH: usize = 256;
+
+/// A `lancer`'d task's status, keyed by the handle `Instr::Spawn` hands
+/// back to the script. Starts `Pending` on the channel the worker thread
+/// will reply on; `attendre_tache`/`tache_prete` drive it to `Done` by
+/// polling or blocking on that channel, and the result is cached there so
+/// reading it twice doesn't need the worker again.
+pub(crate) enum TaskState {
+    Pending(mpsc::Receiver<Result<Value, MeowLangError>>),
+    Done(Result<Value, MeowLangError>),
+}
+
+impl TaskState {
+    /// `tache_prete`: non-blocking. Moves to `Done` if the worker has
+    /// already replied, without waiting for it if not.
+    pub(crate) fn poll(&mut self) -> bool {
+        if let TaskState::Pending(rx) = self {
+            if let Ok(result) = rx.try_recv() {
+                *self = TaskState::Done(result);
+            }
+        }
+        matches!(self, TaskState::Done(_))
+    }
+
+    /// `attendre_tache`: blocks this thread (not the whole process — see
+    /// `TaskPool`) until the worker replies, then returns the (cloned)
+    /// result every time it's called afterwards. Goes through
+    /// `TaskPool::block_until` rather than a bare `rx.recv()` so a pool
+    /// worker waiting on a task of its own keeps draining the pool's queue
+    /// itself instead of just sitting idle — see that method's doc comment.
+    pub(crate) fn wait(&mut self, pool: &TaskPool) -> Result<Value, MeowLangError> {
+        if let TaskState::Pending(rx) = self {
+            let result = pool.block_until(|| match rx.try_recv() {
+                Ok(result) => Some(result),
+                Err(mpsc::TryRecvError::Empty) => None,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    panic!("task worker thread dropped its reply channel without sending a result")
+                },
+            });
+            *self = TaskState::Done(result);
+        }
+        match self {
+            TaskState::Done(result) => result.clone(),
+            TaskState::Pending(_) => unreachable!("just resolved to Done above"),
+        }
+    }
+}
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// How many OS threads a `TaskPool` keeps alive to run `lancer`'d tasks.
+/// Independent `lancer` calls beyond this cap queue instead of minting a
+/// new thread each — `pour chaque x dans gros_intervalle { lancer f(x) }`
+/// now costs `POOL_SIZE` threads, not one per iteration of `gros_intervalle`.
+const POOL_SIZE: usize = 8;
+
+/// A fixed-size pool of worker threads pulling `Job`s off a shared,
+/// unbounded queue, replacing one `thread::spawn` per `lancer` call. A
+/// plain fixed pool has its own failure mode though: a worker running a
+/// task that itself `lancer`s a child and `attendre_tache`s it blocks
+/// that worker until the child runs — if every worker ends up blocked
+/// the same way, waiting on children still sitting in the queue, no
+/// worker is ever free to dequeue them and the pool deadlocks permanently.
+/// `block_until` is how a worker waits on something without reserving
+/// itself for nothing: instead of idling (or, worse, spawning another OS
+/// thread to cover for itself, which just pushes the same problem down
+/// one level of nesting), it keeps pulling and running other queued `Job`s
+/// on itself until the thing it's waiting for is ready. A `lancer`/
+/// `attendre_tache` fan-out this way recurses one call deeper on the
+/// *same* thread's stack per nesting level rather than growing the number
+/// of live OS threads at all, so the pool can't be starved no matter how
+/// deep or wide the fan-out gets.
+///
+/// The queue itself is a `Mutex<VecDeque<Job>>` paired with a `Condvar`
+/// rather than `Mutex<mpsc::Receiver<Job>>`: a waiting `Condvar::wait`
+/// releases the mutex for the duration of the wait and only reacquires it
+/// on wakeup, whereas `Receiver::recv`/`recv_timeout` holds the mutex
+/// guarding it for the whole wait. With up to `POOL_SIZE` permanent
+/// workers and any number of `block_until` callers all polling the same
+/// lock, holding it across a wait lets one thread's tight
+/// lock-wait-unlock-relock cycle starve every other thread's *first*
+/// attempt to acquire it — a real, observed hang, not a theoretical one.
+pub(crate) struct TaskPool {
+    queue: Mutex<VecDeque<Job>>,
+    condvar: Condvar,
+}
+
+impl TaskPool {
+    fn new() -> Arc<TaskPool> {
+        let pool = Arc::new(TaskPool { queue: Mutex::new(VecDeque::new()), condvar: Condvar::new() });
+        for _ in 0..POOL_SIZE {
+            TaskPool::spawn_worker(Arc::clone(&pool));
+        }
+        pool
+    }
+
+    /// The single pool every `VM` in this process shares, created lazily
+    /// on first use so a program that never calls `lancer` never pays for
+    /// it.
+    pub(crate) fn global() -> Arc<TaskPool> {
+        static POOL: OnceLock<Arc<TaskPool>> = OnceLock::new();
+        Arc::clone(POOL.get_or_init(TaskPool::new))
+    }
+
+    fn spawn_worker(pool: Arc<TaskPool>) {
+        thread::spawn(move || loop {
+            pool.run_one_queued_job();
+        });
+    }
+
+    fn submit(&self, job: Job) {
+        self.queue.lock().unwrap().push_back(job);
+        self.condvar.notify_one();
+    }
+
+    /// Dequeues and runs a single `Job` if one is already waiting,
+    /// otherwise sleeps on the condvar for a short while before giving the
+    /// caller (a permanent worker, or a `block_until` caller) a chance to
+    /// re-check whatever else it cares about. The mutex is held only for
+    /// the instant it takes to pop the queue or to put the thread to sleep
+    /// on the condvar — never across the sleep itself — so a `submit` is
+    /// never blocked behind another thread's wait.
+    ///
+    /// Runs the `Job` behind `catch_unwind`: under one-thread-per-task, a
+    /// panicking task only ever unwound its own dedicated thread, so
+    /// nobody but that task's own `attendre_tache` caller saw a broken
+    /// reply channel. Now that any idle caller — a permanent worker, or
+    /// someone else's `attendre`/`attendre_tache` passing through
+    /// `block_until` — can end up running an unrelated task's `Job`, an
+    /// uncaught panic there would otherwise unwind a stack that has
+    /// nothing to do with the task that panicked. `catch_unwind` restores
+    /// the old isolation: the panic still prints via the default hook, the
+    /// `Job`'s `reply_tx` is simply dropped without sending (exactly as
+    /// when the old one-thread-per-task model's thread died mid-task), and
+    /// only a future `attendre_tache` on that specific task ever notices,
+    /// via `TaskState::wait`'s `Disconnected` branch.
+    fn run_one_queued_job(&self) {
+        let mut queue = self.queue.lock().unwrap();
+        match queue.pop_front() {
+            Some(job) => {
+                drop(queue);
+                let _ = panic::catch_unwind(panic::AssertUnwindSafe(job));
+            },
+            None => {
+                let _ = self.condvar.wait_timeout(queue, Duration::from_millis(5)).unwrap();
+            },
+        }
+    }
+
+    /// Blocks the calling thread — a `TaskPool` worker or, for the
+    /// program's main thread, an ordinary caller of `attendre`/
+    /// `attendre_tache` — until `poll_ready` returns `Some`, calling it
+    /// again after every queued `Job` this thread runs in the meantime.
+    /// Checking `poll_ready` first (before looking at the queue) means a
+    /// result that's already sitting there returns immediately without
+    /// running someone else's job first.
+    pub(crate) fn block_until<T>(&self, mut poll_ready: impl FnMut() -> Option<T>) -> T {
+        loop {
+            if let Some(result) = poll_ready() {
+                return result;
+            }
+            self.run_one_queued_job();
+        }
+    }
+}
+
+/// Everything a `TaskPool` worker needs to run one `lancer`'d call, bundled
+/// up so `spawn_task`/`VM::run_task` take one argument instead of each of
+/// these separately.
+struct TaskRequest {
+    program: Arc<CompiledProgram>,
+    interner: Interner,
+    filename: String,
+    source_lines: Vec<String>,
+    function: Symbol,
+    args: Vec<Value>,
+    position: Position,
+}
+
+fn spawn_task(pool: &TaskPool, request: TaskRequest) -> mpsc::Receiver<Result<Value, MeowLangError>> {
+    let (reply_tx, reply_rx) = mpsc::channel();
+    pool.submit(Box::new(move || {
+        let result = VM::run_task(request);
+        let _ = reply_tx.send(result);
+    }));
+    reply_rx
+}
+
+/// Executes a `CompiledProgram` against a single shared operand stack and
+/// an explicit call-frame stack, rather than walking the AST.
+pub struct VM<'p> {
+    program: &'p CompiledProgram,
+    /// The same program as `program`, behind an `Arc` instead of the
+    /// borrow-checker-friendly `'p` reference — `lancer` hands a clone of
+    /// this (not `program`) to a `Job` so a task's worker thread owns a
+    /// handle to the program that outlives this `VM`, instead of being
+    /// bound to `'p`. Keeping both instead of only the `Arc` avoids tying
+    /// every `Frame<'p>` borrow back to `&self`, which is what lets frames
+    /// coexist with `&mut self` elsewhere in this file.
+    program_arc: Arc<CompiledProgram>,
+    filename: String,
+    source_lines: Vec<String>,
+    interner: Interner,
+    stack: Vec<Value>,
+    /// Box–Muller produces two independent standard-normal variates per
+    /// pair of uniforms; `aleatoire_normal` stashes the second one here so
+    /// back-to-back calls don't throw it away.
+    normal_cache: Option<f64>,
+    natives: NativeRegistry,
+    /// `lancer`'d tasks, indexed by the handle returned to the script, each
+    /// run on the process-wide `TaskPool`. See `TaskState`.
+    tasks: Vec<TaskState>,
+    /// The process-wide pool backing `lancer` — see `TaskPool`.
+    pool: Arc<TaskPool>,
+    /// The bound locals of the top-level frame the last time it ran to
+    /// completion, captured just before its slab is dropped — see
+    /// `snapshot_locals`. Used by the REPL (`lib::run_repl`) to carry
+    /// variables forward between otherwise-independent compiled programs.
+    last_locals: Vec<(Symbol, Value)>,
+}
+
+impl<'p> VM<'p> {
+    pub fn new(program: &'p CompiledProgram, program_arc: Arc<CompiledProgram>, interner: Interner, filename: String, source_lines: Vec<String>) -> Self {
+        VM {
+            program,
+            program_arc,
+            filename,
+            source_lines,
+            interner,
+            stack: Vec::new(),
+            normal_cache: None,
+            natives: NativeRegistry::with_stdlib(),
+            tasks: Vec::new(),
+            pool: TaskPool::global(),
+            last_locals: Vec::new(),
+        }
+    }
+
+    /// Runs `function` to completion against its own disposable `VM`, for a
+    /// `TaskPool` worker to call once per `lancer`'d job — see `Instr::Spawn`.
+    /// Builds a fresh `VM` (and so a fresh, thread-local `NativeRegistry`)
+    /// rather than sharing one with the spawning `VM`, since `NativeFn` is
+    /// `Rc`-backed and can't cross threads.
+    fn run_task(request: TaskRequest) -> Result<Value, MeowLangError> {
+        let TaskRequest { program, interner, filename, source_lines, function, args, position } = request;
+        let mut vm = VM::new(&program, Arc::clone(&program), interner, filename, source_lines);
+        // Seed `frames` with the same empty sentinel `Instr::Return` pushes
+        // for a bare top-level `retour` — not an empty `Vec`. `Instr::Return`
+        // treats popping the *last* frame as "the program ended", and
+        // discards the return value rather than pushing it, since nothing
+        // reads `self.stack` after `run()`'s top-level frame pops. With an
+        // empty `frames` to start, `function`'s own `retour` would be that
+        // last frame, so `call_function`'s `self.stack.pop()` below would
+        // find nothing. The sentinel keeps one frame underneath `function`'s
+        // so its `retour` is an ordinary nested return, not the end-of-program
+        // one.
+        let mut frames: Vec<Frame> = vec![Frame { code: &[], positions: &[], slot_names: &[], pc: 0, locals: Vec::new(), try_stack: Vec::new() }];
+        vm.call_function(&mut frames, function, args, &position)
+    }
+
+    /// Reclaims the `Interner` this VM was built with, so a caller that
+    /// needs `Symbol`s to stay stable across several `VM`s (the REPL, one
+    /// fresh `VM` per turn) can feed it back into the next turn's
+    /// `Lexer`/`Parser`/`Compiler` instead of starting a new one.
+    pub fn into_interner(self) -> Interner {
+        self.interner
+    }
+
+    /// The top-level frame's bound locals (name, value) as of the last time
+    /// `run` finished — empty until then. See `last_locals`.
+    pub fn snapshot_locals(&self) -> Vec<(Symbol, Value)> {
+        self.last_locals.clone()
+    }
+
+    fn snapshot_frame(&mut self, frame: &Frame<'p>) {
+        self.last_locals = frame.slot_names.iter()
+            .zip(frame.locals.iter())
+            .filter_map(|(name, value)| value.clone().map(|v| (*name, v)))
+            .collect();
+    }
+
+    /// Lets an embedder add a host function callable from MeowLang source
+    /// under `name`, without forking the interpreter — see
+    /// [`crate::native`]. User-defined MeowLang functions still take
+    /// priority over natives if a script happens to redefine the name.
+    pub fn register_native<F>(&mut self, name: &str, func: F)
+    where
+        F: Fn(&mut NativeContext, &[Value], &Position) -> Result<Value, MeowLangError> + 'static,
+    {
+        self.natives.register(name, func);
+    }
+
+    pub fn run(&mut self) -> Result<(), MeowLangError> {
+        let mut frames: Vec<Frame<'p>> = vec![Frame {
+            code: &self.program.code,
+            positions: &self.program.positions,
+            slot_names: &self.program.slot_names,
+            pc: 0,
+            locals: vec![None; self.program.frame_size],
+            try_stack: Vec::new(),
+        }];
+
+        self.execute(&mut frames, 0)
+    }
+
+    /// Runs `frames` until its depth drops back to `stop_depth`. Used both
+    /// for the whole program (`stop_depth` 0, from `run`) and for a
+    /// synchronous nested call pushed mid-instruction (`call_function`,
+    /// for pipe operators and `plier`/fold) — in both cases a frame
+    /// finishing without an explicit `retour` pushes an implicit
+    /// `Value::None`, except when it's the very last frame of the whole
+    /// program finishing, which just ends execution with nothing to push.
+    fn execute(&mut self, frames: &mut Vec<Frame<'p>>, stop_depth: usize) -> Result<(), MeowLangError> {
+        loop {
+            if frames.len() <= stop_depth {
+                return Ok(());
+            }
+
+            if frames.last().unwrap().pc >= frames.last().unwrap().code.len() {
+                if frames.len() == 1 {
+                    self.snapshot_frame(frames.last().unwrap());
+                }
+                frames.pop();
+                if !frames.is_empty() {
+                    self.stack.push(Value::None);
+                }
+                continue;
+            }
+
+            match self.step(frames) {
+                Ok(()) => {},
+                Err(err) => {
+                    if !self.unwind_to_catch(frames, &err) {
+                        return Err(err);
+                    }
+                },
+            }
+        }
+    }
+
+    /// Calls a builtin or user-defined function synchronously and returns
+    /// its result, recursing into the frame stack and draining it back
+    /// down to `frames`' current depth before returning. Used by the pipe
+    /// operators and `plier`/fold, which need a value back immediately
+    /// rather than letting the function's frame run lazily alongside the
+    /// rest of the program the way a plain `Instr::Call` does.
+    fn call_function(&mut self, frames: &mut Vec<Frame<'p>>, name: Symbol, args: Vec<Value>, position: &Position) -> Result<Value, MeowLangError> {
+        let func_idx = match self.program.function_index.get(&name) {
+            Some(&idx) => idx,
+            None => {
+                let name_str = self.interner.resolve(name).to_string();
+                if let Some(result) = self.call_builtin(&name_str, &args, position)? {
+                    return Ok(result);
+                }
+                return Err(self.error("E600", position).with_extra("func_name".to_string(), name_str));
+            },
+        };
+        let func = &self.program.functions[func_idx];
+        if func.param_count != args.len() {
+            return Err(self.error("E601", position)
+                .with_extra("expected".to_string(), func.param_count.to_string())
+                .with_extra("received".to_string(), args.len().to_string()));
+        }
+
+        if frames.len() >= MAX_CALL_DEPTH {
+            return Err(self.error("E602", position).with_extra("depth".to_string(), MAX_CALL_DEPTH.to_string()));
+        }
+
+        let mut locals: Vec<Option<Value>> = vec![None; func.frame_size];
+        for (slot, arg) in args.into_iter().enumerate() {
+            locals[slot] = Some(arg);
+        }
+
+        let depth = frames.len();
+        frames.push(Frame {
+            code: &func.code,
+            positions: &func.positions,
+            slot_names: &func.slot_names,
+            pc: 0,
+            locals,
+            try_stack: Vec::new(),
+        });
+        self.execute(frames, depth)?;
+        Ok(self.stack.pop().unwrap())
+    }
+
+    /// Executes the current frame's instruction at `pc`. Returns `Err` on
+    /// a runtime error; the caller decides whether a `try`/`except` region
+    /// catches it or it propagates out of `run`.
+    fn step(&mut self, frames: &mut Vec<Frame<'p>>) -> Result<(), MeowLangError> {
+        let frame = frames.last().unwrap();
+        let instr = frame.code[frame.pc].clone();
+        let position = frame.positions[frame.pc].clone();
+
+        let mut advance = true;
+
+        match instr {
+            Instr::PushConst(idx) => self.stack.push(self.program.consts[idx].clone()),
+            Instr::LoadVar(slot) => {
+                let frame = frames.last().unwrap();
+                let value = frame.locals[slot as usize].clone().ok_or_else(|| {
+                    let var_name = self.interner.resolve(frame.slot_names[slot as usize]).to_string();
+                    self.error("E200", &position).with_extra("var_name".to_string(), var_name)
+                })?;
+                self.stack.push(value);
+            },
+            Instr::StoreVar(slot) => {
+                let value = self.stack.pop().unwrap();
+                frames.last_mut().unwrap().locals[slot as usize] = Some(value);
+            },
+            Instr::Add => {
+                let (l, r) = self.pop_pair();
+                let result = self.add_values(&l, &r, &position)?;
+                self.stack.push(result);
+            },
+            Instr::Sub => {
+                let (l, r) = self.pop_pair();
+                let result = self.sub_values(&l, &r, &position)?;
+                self.stack.push(result);
+            },
+            Instr::Mul => {
+                let (l, r) = self.pop_pair();
+                let result = self.mul_values(&l, &r, &position)?;
+                self.stack.push(result);
+            },
+            Instr::Div => {
+                let (l, r) = self.pop_pair();
+                let result = self.div_values(&l, &r, &position)?;
+                self.stack.push(result);
+            },
+            Instr::Mod => self.numeric_binop(&position, |l, r| l % r)?,
+            Instr::Pow => {
+                let (l, r) = self.pop_pair();
+                let result = self.pow_values(&l, &r, &position)?;
+                self.stack.push(result);
+            },
+            Instr::Neg => {
+                let v = self.stack.pop().unwrap();
+                let n = self.as_number(&v, &position)?;
+                self.stack.push(Value::Number(-n));
+            },
+            Instr::Not => {
+                let v = self.stack.pop().unwrap();
+                self.stack.push(Value::Boolean(!v.to_bool()));
+            },
+            Instr::And => {
+                let (l, r) = self.pop_pair();
+                self.stack.push(Value::Boolean(l.to_bool() && r.to_bool()));
+            },
+            Instr::Or => {
+                let (l, r) = self.pop_pair();
+                self.stack.push(Value::Boolean(l.to_bool() || r.to_bool()));
+            },
+            Instr::Cmp(op) => {
+                let (l, r) = self.pop_pair();
+                let result = match op {
+                    CmpOp::Eq => Value::values_equal(&l, &r),
+                    CmpOp::NotEq => !Value::values_equal(&l, &r),
+                    CmpOp::Lt => self.as_number(&l, &position)? < self.as_number(&r, &position)?,
+                    CmpOp::Gt => self.as_number(&l, &position)? > self.as_number(&r, &position)?,
+                    CmpOp::LessEq => self.as_number(&l, &position)? <= self.as_number(&r, &position)?,
+                    CmpOp::GreaterEq => self.as_number(&l, &position)? >= self.as_number(&r, &position)?,
+                };
+                self.stack.push(Value::Boolean(result));
+            },
+            Instr::Jump(target) => {
+                frames.last_mut().unwrap().pc = target;
+                advance = false;
+            },
+            Instr::JumpIfFalse(target) => {
+                let cond = self.stack.pop().unwrap();
+                if !cond.to_bool() {
+                    frames.last_mut().unwrap().pc = target;
+                    advance = false;
+                }
+            },
+            Instr::Call(name, argc) => {
+                let mut args = Vec::with_capacity(argc);
+                for _ in 0..argc {
+                    args.push(self.stack.pop().unwrap());
+                }
+                args.reverse();
+
+                if let Some(&func_idx) = self.program.function_index.get(&name) {
+                    let func = &self.program.functions[func_idx];
+                    if func.param_count != args.len() {
+                        return Err(self.error("E601", &position)
+                            .with_extra("expected".to_string(), func.param_count.to_string())
+                            .with_extra("received".to_string(), args.len().to_string()));
+                    }
+
+                    if frames.len() >= MAX_CALL_DEPTH {
+                        return Err(self.error("E602", &position).with_extra("depth".to_string(), MAX_CALL_DEPTH.to_string()));
+                    }
+
+                    let mut locals: Vec<Option<Value>> = vec![None; func.frame_size];
+                    for (slot, arg) in args.into_iter().enumerate() {
+                        locals[slot] = Some(arg);
+                    }
+
+                    frames.last_mut().unwrap().pc += 1;
+                    frames.push(Frame {
+                        code: &func.code,
+                        positions: &func.positions,
+                        slot_names: &func.slot_names,
+                        pc: 0,
+                        locals,
+                        try_stack: Vec::new(),
+                    });
+                    return Ok(());
+                } else {
+                    let name_str = self.interner.resolve(name).to_string();
+                    if let Some(result) = self.call_builtin(&name_str, &args, &position)? {
+                        self.stack.push(result);
+                    } else {
+                        return Err(self.error("E600", &position)
+                            .with_extra("func_name".to_string(), name_str));
+                    }
+                }
+            },
+            Instr::Return => {
+                let value = self.stack.pop().unwrap();
+                frames.pop();
+                if frames.is_empty() {
+                    // A bare `retour` at the top level ends the program.
+                    frames.push(Frame { code: &[], positions: &[], slot_names: &[], pc: 0, locals: Vec::new(), try_stack: Vec::new() });
+                } else {
+                    self.stack.push(value);
+                }
+                return Ok(());
+            },
+            Instr::MakeList(count) => {
+                let start = self.stack.len() - count;
+                let items = self.stack.split_off(start);
+                self.stack.push(Value::List(items));
+            },
+            Instr::MakeDict(count) => {
+                let start = self.stack.len() - count * 2;
+                let mut raw = self.stack.split_off(start).into_iter();
+                let mut pairs = Vec::with_capacity(count);
+                while let (Some(key), Some(value)) = (raw.next(), raw.next()) {
+                    let key = match key {
+                        Value::String(s) => s,
+                        other => other.to_string(),
+                    };
+                    pairs.push((key, value));
+                }
+                self.stack.push(Value::Dict(pairs));
+            },
+            Instr::Index => {
+                let index = self.stack.pop().unwrap();
+                let object = self.stack.pop().unwrap();
+                match object {
+                    Value::List(items) => {
+                        let idx = self.as_number(&index, &position)? as usize;
+                        let value = items.get(idx).cloned().ok_or_else(|| {
+                            self.error("E700", &position)
+                                .with_extra("index".to_string(), idx.to_string())
+                                .with_extra("size".to_string(), items.len().to_string())
+                        })?;
+                        self.stack.push(value);
+                    },
+                    Value::Dict(pairs) => {
+                        // Keys are coerced to `String` the same way `MakeDict`
+                        // coerces them at construction time, so an
+                        // integer-keyed literal like `dictionnaire(1: "un")`
+                        // stays readable by `d[1]`.
+                        let key = match index {
+                            Value::String(s) => s,
+                            other => other.to_string(),
+                        };
+                        let value = pairs.iter().find(|(k, _)| *k == key).map(|(_, v)| v.clone()).ok_or_else(|| {
+                            self.error("E701", &position).with_extra("key".to_string(), key.clone())
+                        })?;
+                        self.stack.push(value);
+                    },
+                    _ => return Err(self.error("E202", &position)),
+                }
+            },
+            Instr::StoreIndex => {
+                let index = self.stack.pop().unwrap();
+                let container = self.stack.pop().unwrap();
+                let value = self.stack.pop().unwrap();
+                let mutated = match container {
+                    Value::List(mut items) => {
+                        let idx = self.as_number(&index, &position)? as usize;
+                        if idx >= items.len() {
+                            return Err(self.error("E700", &position)
+                                .with_extra("index".to_string(), idx.to_string())
+                                .with_extra("size".to_string(), items.len().to_string()));
+                        }
+                        items[idx] = value;
+                        Value::List(items)
+                    },
+                    Value::Dict(mut pairs) => {
+                        let key = match index {
+                            Value::String(s) => s,
+                            other => other.to_string(),
+                        };
+                        match pairs.iter_mut().find(|(k, _)| *k == key) {
+                            Some(entry) => entry.1 = value,
+                            None => pairs.push((key, value)),
+                        }
+                        Value::Dict(pairs)
+                    },
+                    _ => return Err(self.error("E202", &position)),
+                };
+                self.stack.push(mutated);
+            },
+            Instr::PipeApply(name) => {
+                let value = self.stack.pop().unwrap();
+                let result = self.call_function(frames, name, vec![value], &position)?;
+                self.stack.push(result);
+            },
+            Instr::PipeMap(name) => {
+                let items = self.pop_list(&position)?;
+                let mut mapped = Vec::with_capacity(items.len());
+                for item in items {
+                    mapped.push(self.call_function(frames, name, vec![item], &position)?);
+                }
+                self.stack.push(Value::List(mapped));
+            },
+            Instr::PipeFilter(name) => {
+                let items = self.pop_list(&position)?;
+                let mut kept = Vec::with_capacity(items.len());
+                for item in items {
+                    let verdict = self.call_function(frames, name, vec![item.clone()], &position)?;
+                    if verdict.to_bool() {
+                        kept.push(item);
+                    }
+                }
+                self.stack.push(Value::List(kept));
+            },
+            Instr::Concat => {
+                let right = self.pop_list(&position)?;
+                let left = self.pop_list(&position)?;
+                let mut items = left;
+                items.extend(right);
+                self.stack.push(Value::List(items));
+            },
+            Instr::Interpolate(count) => {
+                let start = self.stack.len() - count;
+                let joined: String = self.stack.drain(start..).map(|v| v.to_string()).collect();
+                self.stack.push(Value::String(joined));
+            },
+            Instr::Fold(name) => {
+                let mut accumulator = self.stack.pop().unwrap();
+                let items = self.pop_list(&position)?;
+                for item in items {
+                    accumulator = self.call_function(frames, name, vec![accumulator, item], &position)?;
+                }
+                self.stack.push(accumulator);
+            },
+            Instr::Spawn(name, argc) => {
+                let mut args = Vec::with_capacity(argc);
+                for _ in 0..argc {
+                    args.push(self.stack.pop().unwrap());
+                }
+                args.reverse();
+                let reply_rx = spawn_task(&self.pool, TaskRequest {
+                    program: Arc::clone(&self.program_arc),
+                    interner: self.interner.clone(),
+                    filename: self.filename.clone(),
+                    source_lines: self.source_lines.clone(),
+                    function: name,
+                    args,
+                    position: position.clone(),
+                });
+                self.tasks.push(TaskState::Pending(reply_rx));
+                self.stack.push(Value::Integer((self.tasks.len() - 1) as i64));
+            },
+            Instr::TryStart(handlers) => {
+                let stack_len = self.stack.len();
+                frames.last_mut().unwrap().try_stack.push(TryRegion { handlers, stack_len });
+            },
+            Instr::TryEnd => {
+                frames.last_mut().unwrap().try_stack.pop();
+            },
+            Instr::Pop => {
+                self.stack.pop();
+            },
+        }
+
+        if advance {
+            frames.last_mut().unwrap().pc += 1;
+        }
+        Ok(())
+    }
+
+    /// On a runtime error, unwinds the current call frame's `try_stack`
+    /// (try/except doesn't cross function-call boundaries) looking for a
+    /// region with a handler whose `code_filter` matches the error — `None`
+    /// always matches. A region with no matching handler is consumed and
+    /// the search continues at the next outer one, so an error a handler
+    /// doesn't want re-propagates instead of being swallowed. On a match,
+    /// the operand stack is truncated back to where the `essayer` began,
+    /// the caught error is pushed as a `Value::Dict`, and the frame jumps
+    /// to the handler's body. Returns `false` if nothing catches it.
+    fn unwind_to_catch(&mut self, frames: &mut Vec<Frame<'p>>, error: &MeowLangError) -> bool {
+        let code = error.error_def.code;
+        let frame = match frames.last_mut() {
+            Some(frame) => frame,
+            None => return false,
+        };
+        while let Some(region) = frame.try_stack.pop() {
+            if let Some(handler) = region.handlers.iter().find(|h| h.code_filter.as_deref().map_or(true, |f| f == code)) {
+                self.stack.truncate(region.stack_len);
+                self.stack.push(self.error_to_value(error));
+                frame.pc = handler.pc;
+                return true;
+            }
+        }
+        false
+    }
+
+    fn error_to_value(&self, error: &MeowLangError) -> Value {
+        Value::Dict(vec![
+            ("code".to_string(), Value::String(error.error_def.code.to_string())),
+            ("message".to_string(), Value::String(error.message())),
+            ("ligne".to_string(), Value::Integer(error.line as i64)),
+            ("colonne".to_string(), Value::Integer(error.column as i64)),
+        ])
+    }
+
+    fn pop_list(&mut self, position: &Position) -> Result<Vec<Value>, MeowLangError> {
+        match self.stack.pop().unwrap() {
+            Value::List(items) => Ok(items),
+            _ => Err(self.error("E202", position)),
+        }
+    }
+
+    fn pop_pair(&mut self) -> (Value, Value) {
+        let r = self.stack.pop().unwrap();
+        let l = self.stack.pop().unwrap();
+        (l, r)
+    }
+
+    fn as_number(&self, value: &Value, position: &Position) -> Result<f64, MeowLangError> {
+        value.to_number().map_err(|_| self.error("E202", position))
+    }
+
+    fn numeric_binop(&mut self, position: &Position, op: impl Fn(f64, f64) -> f64) -> Result<(), MeowLangError> {
+        let (l, r) = self.pop_pair();
+        let ln = self.as_number(&l, position)?;
+        let rn = self.as_number(&r, position)?;
+        self.stack.push(Value::Number(op(ln, rn)));
+        Ok(())
+    }
+
+    /// Reduces `num/den` by their `gcd` and normalizes the sign onto the
+    /// numerator, collapsing to `Integer` when the denominator comes out to
+    /// `1`. Callers are expected to have already rejected `den == 0`.
+    fn make_rational(&self, num: i64, den: i64) -> Value {
+        let sign = if den < 0 { -1 } else { 1 };
+        let num = num * sign;
+        let den = den * sign;
+        let g = gcd(num, den);
+        let num = num / g;
+        let den = den / g;
+        if den == 1 { Value::Integer(num) } else { Value::Rational { num, den } }
+    }
+
+    fn as_complex(&self, value: &Value, position: &Position) -> Result<(f64, f64), MeowLangError> {
+        match value {
+            Value::Complex { re, im } => Ok((*re, *im)),
+            Value::Integer(i) => Ok((*i as f64, 0.0)),
+            Value::Number(n) => Ok((*n, 0.0)),
+            Value::Rational { num, den } => Ok((*num as f64 / *den as f64, 0.0)),
+            _ => Err(self.error("E202", position)),
+        }
+    }
+
+    fn add_values(&self, l: &Value, r: &Value, position: &Position) -> Result<Value, MeowLangError> {
+        match (l, r) {
+            (Value::String(ls), Value::String(rs)) => Ok(Value::String(format!("{}{}", ls, rs))),
+            (Value::String(ls), other) => Ok(Value::String(format!("{}{}", ls, other.to_string()))),
+            (other, Value::String(rs)) => Ok(Value::String(format!("{}{}", other.to_string(), rs))),
+            _ => {
+                if let (Some((ln, ld)), Some((rn, rd))) = (l.as_rational(), r.as_rational()) {
+                    return Ok(self.make_rational(ln * rd + rn * ld, ld * rd));
+                }
+                if matches!(l, Value::Complex { .. }) || matches!(r, Value::Complex { .. }) {
+                    let (lre, lim) = self.as_complex(l, position)?;
+                    let (rre, rim) = self.as_complex(r, position)?;
+                    return Ok(Value::Complex { re: lre + rre, im: lim + rim });
+                }
+                let ln = self.as_number(l, position)?;
+                let rn = self.as_number(r, position)?;
+                Ok(Value::Number(ln + rn))
+            },
+        }
+    }
+
+    fn sub_values(&self, l: &Value, r: &Value, position: &Position) -> Result<Value, MeowLangError> {
+        if let (Some((ln, ld)), Some((rn, rd))) = (l.as_rational(), r.as_rational()) {
+            return Ok(self.make_rational(ln * rd - rn * ld, ld * rd));
+        }
+        if matches!(l, Value::Complex { .. }) || matches!(r, Value::Complex { .. }) {
+            let (lre, lim) = self.as_complex(l, position)?;
+            let (rre, rim) = self.as_complex(r, position)?;
+            return Ok(Value::Complex { re: lre - rre, im: lim - rim });
+        }
+        let ln = self.as_number(l, position)?;
+        let rn = self.as_number(r, position)?;
+        Ok(Value::Number(ln - rn))
+    }
+
+    fn mul_values(&self, l: &Value, r: &Value, position: &Position) -> Result<Value, MeowLangError> {
+        if let (Some((ln, ld)), Some((rn, rd))) = (l.as_rational(), r.as_rational()) {
+            return Ok(self.make_rational(ln * rn, ld * rd));
+        }
+        if matches!(l, Value::Complex { .. }) || matches!(r, Value::Complex { .. }) {
+            let (lre, lim) = self.as_complex(l, position)?;
+            let (rre, rim) = self.as_complex(r, position)?;
+            return Ok(Value::Complex { re: lre * rre - lim * rim, im: lre * rim + lim * rre });
+        }
+        let ln = self.as_number(l, position)?;
+        let rn = self.as_number(r, position)?;
+        Ok(Value::Number(ln * rn))
+    }
+
+    fn div_values(&self, l: &Value, r: &Value, position: &Position) -> Result<Value, MeowLangError> {
+        if let (Some((ln, ld)), Some((rn, rd))) = (l.as_rational(), r.as_rational()) {
+            if rn == 0 {
+                return Err(self.error("E500", position));
+            }
+            return Ok(self.make_rational(ln * rd, ld * rn));
+        }
+        if matches!(l, Value::Complex { .. }) || matches!(r, Value::Complex { .. }) {
+            let (lre, lim) = self.as_complex(l, position)?;
+            let (rre, rim) = self.as_complex(r, position)?;
+            let denom = rre * rre + rim * rim;
+            if denom == 0.0 {
+                return Err(self.error("E500", position));
+            }
+            return Ok(Value::Complex {
+                re: (lre * rre + lim * rim) / denom,
+                im: (lim * rre - lre * rim) / denom,
+            });
+        }
+        let ln = self.as_number(l, position)?;
+        let rn = self.as_number(r, position)?;
+        if rn == 0.0 {
+            return Err(self.error("E500", position));
+        }
+        Ok(Value::Number(ln / rn))
+    }
+
+    fn pow_values(&self, l: &Value, r: &Value, position: &Position) -> Result<Value, MeowLangError> {
+        if let (Some((bn, bd)), Value::Integer(exp)) = (l.as_rational(), r) {
+            if let Ok(e) = u32::try_from(exp.unsigned_abs()) {
+                if let (Some(num), Some(den)) = (bn.checked_pow(e), bd.checked_pow(e)) {
+                    return Ok(if *exp >= 0 {
+                        self.make_rational(num, den)
+                    } else {
+                        if num == 0 {
+                            return Err(self.error("E500", position));
+                        }
+                        self.make_rational(den, num)
+                    });
+                }
+            }
+        }
+        if matches!(l, Value::Complex { .. }) || matches!(r, Value::Complex { .. }) {
+            let (lre, lim) = self.as_complex(l, position)?;
+            let (rre, _) = self.as_complex(r, position)?;
+            // Only integral complex-base exponents are supported; repeated
+            // multiplication keeps things simple and exact for small powers.
+            let mut result = (1.0, 0.0);
+            let mut base = (lre, lim);
+            let mut e = rre.abs() as i64;
+            while e > 0 {
+                result = (result.0 * base.0 - result.1 * base.1, result.0 * base.1 + result.1 * base.0);
+                e -= 1;
+            }
+            if rre < 0.0 {
+                let denom = result.0 * result.0 + result.1 * result.1;
+                if denom == 0.0 {
+                    return Err(self.error("E500", position));
+                }
+                result = (result.0 / denom, -result.1 / denom);
+            }
+            return Ok(Value::Complex { re: result.0, im: result.1 });
+        }
+
+        let ln = self.as_number(l, position)?;
+        let rn = self.as_number(r, position)?;
+        let result = ln.powf(rn);
+        if result.is_nan() && ln < 0.0 {
+            // A negative base with a fractional exponent has no real root —
+            // fall back to the complex result via polar form instead of NaN.
+            let magnitude = ln.abs().powf(rn);
+            let angle = std::f64::consts::PI * rn;
+            return Ok(Value::Complex { re: magnitude * angle.cos(), im: magnitude * angle.sin() });
+        }
+        Ok(Value::Number(result))
+    }
+
+    fn error(&self, code: &str, position: &Position) -> MeowLangError {
+        MeowLangError::new(ErrorCatalog::get(code), self.filename.clone(), position.line, position.column)
+            .with_context(&self.source_lines)
+    }
+
+    /// Looks up `name` in the native registry and, if present, runs it
+    /// against a short-lived [`NativeContext`] borrowing just the pieces of
+    /// `self` a native function can touch (error construction, the Box–Muller
+    /// cache) — see `crate::native`.
+    fn call_builtin(&mut self, name: &str, args: &[Value], position: &Position) -> Result<Option<Value>, MeowLangError> {
+        let Some(func) = self.natives.get(name) else { return Ok(None) };
+        let mut ctx = NativeContext {
+            filename: &self.filename,
+            source_lines: &self.source_lines,
+            normal_cache: &mut self.normal_cache,
+            tasks: &mut self.tasks,
+            pool: &self.pool,
+        };
+        func(&mut ctx, args, position).map(Some)
+    }
+}